@@ -0,0 +1,8 @@
+-- Request/response body sizes for service checks
+-- Used for capacity planning and spotting anomalies (error pages, data leaks)
+
+-- Request body size in bytes (NULL for checks recorded before this migration)
+ALTER TABLE service_checks ADD COLUMN request_bytes INTEGER;
+
+-- Response body size in bytes (NULL if no response was received)
+ALTER TABLE service_checks ADD COLUMN response_bytes INTEGER;
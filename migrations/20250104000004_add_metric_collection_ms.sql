@@ -0,0 +1,5 @@
+-- Agent poll duration for metrics
+-- Growing poll latency hints at agent or network degradation
+
+-- Milliseconds spent polling the agent (NULL for imported data and rows recorded before this migration)
+ALTER TABLE metrics ADD COLUMN collection_ms INTEGER;
@@ -0,0 +1,17 @@
+-- Compacted service check history
+-- Consecutive checks with identical status are collapsed into one run, so
+-- uptime and incident queries over long ranges don't scan every raw check.
+-- Raw checks can then be retained short-term only.
+
+CREATE TABLE IF NOT EXISTS service_check_runs (
+    service_name TEXT NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('up', 'down', 'degraded')),
+    start_time INTEGER NOT NULL,  -- Timestamp of the first check in the run
+    end_time INTEGER NOT NULL,    -- Timestamp of the last check in the run
+    check_count INTEGER NOT NULL,
+    PRIMARY KEY (service_name, start_time)
+) STRICT;
+
+-- Index for range queries and finding the latest run per service
+CREATE INDEX IF NOT EXISTS idx_service_check_runs_end
+    ON service_check_runs(service_name, end_time);
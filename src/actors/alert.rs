@@ -17,20 +17,36 @@
 //! ```
 //!
 //! This prevents alerts from firing on transient spikes.
+//!
+//! ## Channel Health Checks
+//!
+//! When spawned with an [`AlertHealthConfig`], the actor validates every configured
+//! alert channel at startup and then periodically in a background task, so a broken
+//! webhook URL is noticed before an alert actually needs to go out. Results are logged
+//! and available via [`AlertHandle::channel_health`].
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::Utc;
-use tokio::sync::{broadcast, mpsc};
-use tracing::{debug, instrument, trace, warn};
+use reqwest::Client;
+use tokio::sync::{RwLock, broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, trace, warn};
 
 use crate::{
-    alerts::AlertManager,
-    config::{ResolvedLimit, ResolvedServerConfig, ResolvedServiceConfig},
+    alerts::{AlertManager, check_alert_channel},
+    config::{
+        Alert, AlertHealthConfig, ResolvedInventoryLimit, ResolvedLatencyLimit, ResolvedLimit,
+        ResolvedServerConfig, ResolvedServiceConfig,
+    },
     monitors::resources::ResourceEvaluation,
 };
 
-use super::messages::{AlertCommand, AlertState, MetricEvent, ServiceCheckEvent, ServiceStatus};
+use super::messages::{
+    AlertChannelHealth, AlertCommand, AlertState, MetricEvent, ServiceCheckEvent, ServiceStatus,
+};
 
 /// Per-server alert state
 #[derive(Debug, Clone)]
@@ -46,6 +62,15 @@ struct ServerAlertState {
 
     /// CPU usage grace counter
     usage_grace_counter: usize,
+
+    /// Poll latency grace counter
+    latency_grace_counter: usize,
+
+    /// Recent poll durations in milliseconds (for trend detection)
+    recent_latencies: VecDeque<u64>,
+
+    /// Last observed CPU core count and architecture
+    cpu_inventory: Option<(usize, String)>,
 }
 
 /// Per-service alert state (Phase 3)
@@ -83,6 +108,12 @@ pub struct AlertActor {
 
     /// Whether alerts are muted
     muted: bool,
+
+    /// Latest alert channel health check results
+    channel_health: Arc<RwLock<Vec<AlertChannelHealth>>>,
+
+    /// Background task running periodic channel health checks
+    health_task: Option<JoinHandle<()>>,
 }
 
 impl AlertActor {
@@ -99,6 +130,8 @@ impl AlertActor {
             metric_rx,
             service_check_rx,
             muted: false,
+            channel_health: Arc::new(RwLock::new(Vec::new())),
+            health_task: None,
         }
     }
 
@@ -106,7 +139,7 @@ impl AlertActor {
     ///
     /// This should be called for each server before metrics start flowing.
     pub fn register_server(&mut self, config: ResolvedServerConfig) {
-        let server_id = format!("{}:{}", config.ip, config.port);
+        let server_id = config.server_id();
         let alert_manager = AlertManager::new(config.clone());
 
         self.servers.insert(
@@ -116,6 +149,9 @@ impl AlertActor {
                 alert_manager,
                 temp_grace_counter: 0,
                 usage_grace_counter: 0,
+                latency_grace_counter: 0,
+                recent_latencies: VecDeque::new(),
+                cpu_inventory: None,
             },
         );
     }
@@ -151,6 +187,83 @@ impl AlertActor {
         );
     }
 
+    /// Collect all distinct alert channels used by registered servers and services
+    fn alert_channels(&self) -> Vec<Alert> {
+        let server_alerts = self.servers.values().flat_map(|state| {
+            state.config.limits.iter().flat_map(|limits| {
+                [&limits.temperature, &limits.usage]
+                    .into_iter()
+                    .filter_map(|limit| limit.as_ref()?.alert.clone())
+                    .chain(limits.latency.as_ref().and_then(|l| l.alert.clone()))
+                    .chain(limits.inventory.as_ref().and_then(|l| l.alert.clone()))
+            })
+        });
+        let service_alerts = self
+            .services
+            .values()
+            .filter_map(|state| state.config.alert.clone());
+
+        let mut channels: Vec<Alert> = Vec::new();
+        for alert in server_alerts.chain(service_alerts) {
+            if !channels
+                .iter()
+                .any(|known| known.target() == alert.target())
+            {
+                channels.push(alert);
+            }
+        }
+        channels
+    }
+
+    /// Start validating alert channels at startup and every `config.interval` seconds
+    pub fn start_health_checks(&mut self, config: &AlertHealthConfig) {
+        if !config.enabled {
+            debug!("alert channel health checks disabled");
+            return;
+        }
+
+        let channels = self.alert_channels();
+        if channels.is_empty() {
+            debug!("no alert channels configured, skipping health checks");
+            return;
+        }
+
+        let results = self.channel_health.clone();
+        let interval = Duration::from_secs(config.interval.max(1));
+        let timeout = Duration::from_secs(config.timeout);
+
+        self.health_task = Some(tokio::spawn(async move {
+            let client = Client::new();
+            let mut ticker = tokio::time::interval(interval);
+
+            loop {
+                // First tick completes immediately (startup check)
+                ticker.tick().await;
+
+                let mut report = Vec::with_capacity(channels.len());
+                for alert in &channels {
+                    let health = check_alert_channel(&client, alert, timeout).await;
+                    if health.healthy {
+                        info!(
+                            "alert channel {} ({}) is healthy",
+                            health.target, health.channel
+                        );
+                    } else {
+                        warn!(
+                            "alert channel {} ({}) is unhealthy: {}",
+                            health.target,
+                            health.channel,
+                            health.error.as_deref().unwrap_or("unknown error")
+                        );
+                    }
+                    report.push(health);
+                }
+
+                *results.write().await = report;
+            }
+        }));
+    }
+
     /// Run the actor's main loop
     #[instrument(skip(self))]
     pub async fn run(mut self) {
@@ -216,6 +329,24 @@ impl AlertActor {
                             self.muted = false;
                         }
 
+                        AlertCommand::GetChannelHealth { respond_to } => {
+                            let report = self.channel_health.read().await.clone();
+                            let _ = respond_to.send(report);
+                        }
+
+                        AlertCommand::SeedInventory { server_id, inventory, respond_to } => {
+                            if let Some(state) = self.servers.get_mut(&server_id)
+                                && state.cpu_inventory.is_none()
+                            {
+                                trace!(
+                                    "{server_id}: seeded CPU inventory: {} x {}",
+                                    inventory.0, inventory.1
+                                );
+                                state.cpu_inventory = Some(inventory);
+                            }
+                            let _ = respond_to.send(());
+                        }
+
                         AlertCommand::Shutdown => {
                             debug!("received shutdown command");
                             break;
@@ -231,6 +362,10 @@ impl AlertActor {
             }
         }
 
+        if let Some(task) = self.health_task.take() {
+            task.abort();
+        }
+
         debug!("alert actor stopped");
     }
 
@@ -260,6 +395,137 @@ impl AlertActor {
         if let Some(limit) = limits.usage {
             Self::evaluate_cpu_usage(&event, state, &limit).await;
         }
+
+        // Evaluate agent poll latency
+        if let Some(limit) = limits.latency {
+            Self::evaluate_latency(&event, state, &limit).await;
+        }
+
+        // Evaluate CPU inventory changes
+        if let Some(limit) = limits.inventory {
+            Self::evaluate_inventory(&event, state, &limit).await;
+        }
+    }
+
+    /// Compare CPU core count and architecture to the previously observed ones
+    ///
+    /// The baseline is the last observed inventory, or the seeded one from
+    /// storage (see [`AlertHandle::seed_inventory_from_storage`]) after a restart.
+    /// Changes to an allowlisted value are accepted silently. Either way, the
+    /// new inventory becomes the baseline, so each change alerts once.
+    async fn evaluate_inventory(
+        event: &MetricEvent,
+        state: &mut ServerAlertState,
+        limit: &ResolvedInventoryLimit,
+    ) {
+        let cpus = &event.metrics.cpus;
+        let current = (cpus.total, cpus.arch.clone());
+
+        let Some((previous_total, previous_arch)) = state.cpu_inventory.replace(current) else {
+            trace!(
+                "recorded initial CPU inventory: {} x {}",
+                cpus.total, cpus.arch
+            );
+            return;
+        };
+
+        let mut changes = Vec::new();
+        if previous_total != cpus.total && !limit.allowed_cpus.contains(&cpus.total) {
+            changes.push(format!("cores {} → {}", previous_total, cpus.total));
+        }
+        if previous_arch != cpus.arch && !limit.allowed_arch.contains(&cpus.arch) {
+            changes.push(format!("arch {} → {}", previous_arch, cpus.arch));
+        }
+
+        if changes.is_empty() {
+            if previous_total != cpus.total || previous_arch != cpus.arch {
+                info!(
+                    "{}: allowed CPU inventory change to {} x {}",
+                    event.server_id, cpus.total, cpus.arch
+                );
+            }
+            return;
+        }
+
+        warn!(
+            "{}: unexpected CPU inventory change ({})",
+            event.server_id,
+            changes.join(", ")
+        );
+
+        state.alert_manager.send_inventory_alert(&changes).await;
+    }
+
+    /// Evaluate agent poll latency against threshold and trend
+    async fn evaluate_latency(
+        event: &MetricEvent,
+        state: &mut ServerAlertState,
+        limit: &ResolvedLatencyLimit,
+    ) {
+        let Some(collection_ms) = event.collection_ms else {
+            return;
+        };
+
+        // Keep just enough samples to see `trend` consecutive increases
+        if let Some(trend) = limit.trend {
+            state.recent_latencies.push_back(collection_ms);
+            while state.recent_latencies.len() > trend + 1 {
+                state.recent_latencies.pop_front();
+            }
+        }
+
+        let over_limit = limit.limit.is_some_and(|max| collection_ms >= max);
+        let trending = limit
+            .trend
+            .is_some_and(|trend| is_trending_up(&state.recent_latencies, trend));
+        let grace = limit.grace.unwrap_or_default();
+
+        let evaluation = ResourceEvaluation::evaluate_condition(
+            over_limit || trending,
+            grace,
+            state.latency_grace_counter,
+        );
+
+        trace!(
+            "latency evaluation: {collection_ms}ms (over limit: {over_limit}, trending: {trending}), grace {}/{} → {evaluation:?}",
+            state.latency_grace_counter, grace
+        );
+
+        match evaluation {
+            ResourceEvaluation::Ok => {
+                state.latency_grace_counter = 0;
+            }
+
+            ResourceEvaluation::Exceeding => {
+                state.latency_grace_counter += 1;
+            }
+
+            ResourceEvaluation::StartsToExceed => {
+                state.latency_grace_counter += 1;
+                debug!(
+                    "{}: poll latency degraded ({collection_ms}ms, trending: {trending})",
+                    event.server_id
+                );
+
+                state
+                    .alert_manager
+                    .send_latency_alert(evaluation, collection_ms, trending)
+                    .await;
+            }
+
+            ResourceEvaluation::BackToOk => {
+                debug!(
+                    "{}: poll latency recovered ({collection_ms}ms)",
+                    event.server_id
+                );
+                state.latency_grace_counter = 0;
+
+                state
+                    .alert_manager
+                    .send_latency_alert(evaluation, collection_ms, false)
+                    .await;
+            }
+        }
     }
 
     /// Evaluate temperature against limit
@@ -473,11 +739,21 @@ impl AlertActor {
             server_id: server_id.to_string(),
             cpu_consecutive_exceeds: state.usage_grace_counter,
             temp_consecutive_exceeds: state.temp_grace_counter,
+            latency_consecutive_exceeds: state.latency_grace_counter,
             last_evaluation: Utc::now(),
         })
     }
 }
 
+/// Whether the last `trend + 1` latencies are strictly increasing
+fn is_trending_up(latencies: &VecDeque<u64>, trend: usize) -> bool {
+    latencies.len() > trend
+        && latencies
+            .iter()
+            .zip(latencies.iter().skip(1))
+            .all(|(previous, next)| next > previous)
+}
+
 /// Handle for controlling the AlertActor
 #[derive(Clone)]
 pub struct AlertHandle {
@@ -497,6 +773,36 @@ impl AlertHandle {
         services: Vec<ResolvedServiceConfig>,
         metric_rx: broadcast::Receiver<MetricEvent>,
         service_check_rx: broadcast::Receiver<ServiceCheckEvent>,
+    ) -> Self {
+        Self::spawn_inner(servers, services, metric_rx, service_check_rx, None)
+    }
+
+    /// Spawn a new alert actor that also validates its alert channels
+    ///
+    /// Channels are checked once at startup and then every `health_config.interval`
+    /// seconds. See [`AlertHandle::channel_health`] for the results.
+    pub fn spawn_with_health_check(
+        servers: Vec<ResolvedServerConfig>,
+        services: Vec<ResolvedServiceConfig>,
+        metric_rx: broadcast::Receiver<MetricEvent>,
+        service_check_rx: broadcast::Receiver<ServiceCheckEvent>,
+        health_config: &AlertHealthConfig,
+    ) -> Self {
+        Self::spawn_inner(
+            servers,
+            services,
+            metric_rx,
+            service_check_rx,
+            Some(health_config),
+        )
+    }
+
+    fn spawn_inner(
+        servers: Vec<ResolvedServerConfig>,
+        services: Vec<ResolvedServiceConfig>,
+        metric_rx: broadcast::Receiver<MetricEvent>,
+        service_check_rx: broadcast::Receiver<ServiceCheckEvent>,
+        health_config: Option<&AlertHealthConfig>,
     ) -> Self {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
@@ -512,6 +818,10 @@ impl AlertHandle {
             actor.register_service(config);
         }
 
+        if let Some(health_config) = health_config {
+            actor.start_health_checks(health_config);
+        }
+
         tokio::spawn(actor.run());
 
         Self { sender: cmd_tx }
@@ -544,6 +854,76 @@ impl AlertHandle {
         let _ = self.sender.send(AlertCommand::UnmuteAlerts).await;
     }
 
+    /// Get the latest alert channel health check results
+    ///
+    /// Empty if health checks are disabled or have not completed yet.
+    pub async fn channel_health(&self) -> Vec<AlertChannelHealth> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        if self
+            .sender
+            .send(AlertCommand::GetChannelHealth { respond_to: tx })
+            .await
+            .is_err()
+        {
+            return Vec::new();
+        }
+
+        rx.await.unwrap_or_default()
+    }
+
+    /// Seed a server's CPU inventory baseline
+    ///
+    /// Returns once the baseline is in place, so metrics published afterwards
+    /// are compared against it.
+    pub async fn seed_inventory(&self, server_id: String, inventory: (usize, String)) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        if self
+            .sender
+            .send(AlertCommand::SeedInventory {
+                server_id,
+                inventory,
+                respond_to: tx,
+            })
+            .await
+            .is_ok()
+        {
+            let _ = rx.await;
+        }
+    }
+
+    /// Seed CPU inventory baselines from the latest stored metric per server
+    ///
+    /// Without this, the first sample after a hub restart becomes the baseline
+    /// and a change that happened while the hub was down never alerts. Only
+    /// servers with an inventory limit are seeded.
+    #[cfg(feature = "storage-sqlite")]
+    pub async fn seed_inventory_from_storage(
+        &self,
+        storage: &crate::actors::storage::StorageHandle,
+        servers: &[ResolvedServerConfig],
+    ) {
+        let inventory_servers = servers.iter().filter(|config| {
+            config
+                .limits
+                .as_ref()
+                .is_some_and(|limits| limits.inventory.is_some())
+        });
+
+        for config in inventory_servers {
+            let server_id = config.server_id();
+            match storage.query_latest(server_id.clone(), 1).await {
+                Ok(rows) => {
+                    if let Some(row) = rows.into_iter().next() {
+                        let cpus = row.metadata.cpus;
+                        self.seed_inventory(server_id, (cpus.total, cpus.arch))
+                            .await;
+                    }
+                }
+                Err(e) => debug!("{server_id}: no stored CPU inventory to seed from: {e}"),
+            }
+        }
+    }
+
     /// Shutdown the alert actor
     pub async fn shutdown(&self) {
         let _ = self.sender.send(AlertCommand::Shutdown).await;
@@ -582,6 +962,8 @@ mod tests {
                     grace: Some(5),
                     alert: None,
                 }),
+                latency: None,
+                inventory: None,
             }),
         }
     }
@@ -632,6 +1014,7 @@ mod tests {
                 metrics: create_test_metrics(50.0, Some(65.0)), // Below 70°C
                 timestamp: Utc::now(),
                 display_name: "Test".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -648,6 +1031,7 @@ mod tests {
                 metrics: create_test_metrics(50.0, Some(75.0)), // Above 70°C
                 timestamp: Utc::now(),
                 display_name: "Test".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -663,6 +1047,7 @@ mod tests {
             metrics: create_test_metrics(50.0, Some(75.0)),
             timestamp: Utc::now(),
             display_name: "Test".to_string(),
+            collection_ms: None,
         };
         metric_tx.send(event).unwrap();
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -691,6 +1076,7 @@ mod tests {
                 metrics: create_test_metrics(50.0, Some(75.0)), // Above temp limit
                 timestamp: Utc::now(),
                 display_name: "Test".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -707,6 +1093,7 @@ mod tests {
                 metrics: create_test_metrics(85.0, Some(75.0)), // Above CPU limit (80%)
                 timestamp: Utc::now(),
                 display_name: "Test".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -736,6 +1123,7 @@ mod tests {
                 metrics: create_test_metrics(85.0, Some(50.0)), // CPU above limit
                 timestamp: Utc::now(),
                 display_name: "Test".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -750,6 +1138,7 @@ mod tests {
             metrics: create_test_metrics(50.0, Some(50.0)), // Below limit
             timestamp: Utc::now(),
             display_name: "Test".to_string(),
+            collection_ms: None,
         };
         metric_tx.send(event).unwrap();
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -760,6 +1149,74 @@ mod tests {
         handle.shutdown().await;
     }
 
+    #[tokio::test]
+    async fn test_latency_limit_and_trend_increment_grace_counter() {
+        let (metric_tx, metric_rx) = broadcast::channel(16);
+        let (_service_tx, service_rx) = broadcast::channel(16);
+        let mut config = create_test_server_config("127.0.0.1", 3000);
+        if let Some(limits) = config.limits.as_mut() {
+            limits.latency = Some(ResolvedLatencyLimit {
+                limit: Some(1000),
+                trend: Some(2),
+                grace: Some(5),
+                alert: None,
+            });
+        }
+        let server_id = "127.0.0.1:3000".to_string();
+
+        let handle = AlertHandle::spawn(vec![config], vec![], metric_rx, service_rx);
+
+        let send_latency = |collection_ms: u64| {
+            metric_tx
+                .send(MetricEvent {
+                    server_id: server_id.clone(),
+                    metrics: create_test_metrics(50.0, Some(50.0)),
+                    timestamp: Utc::now(),
+                    display_name: "Test".to_string(),
+                    collection_ms: Some(collection_ms),
+                })
+                .unwrap();
+        };
+
+        // Two samples are not enough to establish a trend of 2
+        for collection_ms in [100, 200] {
+            send_latency(collection_ms);
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+        let state = handle.get_state(server_id.clone()).await.unwrap();
+        assert_eq!(state.latency_consecutive_exceeds, 0);
+
+        // Third strictly increasing sample completes the trend
+        send_latency(300);
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let state = handle.get_state(server_id.clone()).await.unwrap();
+        assert_eq!(state.latency_consecutive_exceeds, 1);
+
+        // A faster poll breaks the trend and resets the counter
+        send_latency(150);
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let state = handle.get_state(server_id.clone()).await.unwrap();
+        assert_eq!(state.latency_consecutive_exceeds, 0);
+
+        // Crossing the absolute limit counts regardless of trend
+        send_latency(1500);
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let state = handle.get_state(server_id.clone()).await.unwrap();
+        assert_eq!(state.latency_consecutive_exceeds, 1);
+
+        handle.shutdown().await;
+    }
+
+    #[test]
+    fn test_is_trending_up() {
+        let latencies = VecDeque::from([100, 150, 300]);
+        assert!(is_trending_up(&latencies, 2));
+        assert!(!is_trending_up(&latencies, 3));
+
+        let latencies = VecDeque::from([100, 150, 150]);
+        assert!(!is_trending_up(&latencies, 2));
+    }
+
     #[tokio::test]
     async fn test_mute_prevents_alert_processing() {
         let (metric_tx, metric_rx) = broadcast::channel(16);
@@ -780,6 +1237,7 @@ mod tests {
                 metrics: create_test_metrics(95.0, Some(85.0)), // Way above limits
                 timestamp: Utc::now(),
                 display_name: "Test".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -814,6 +1272,7 @@ mod tests {
                 metrics: create_test_metrics(85.0, Some(75.0)),
                 timestamp: Utc::now(),
                 display_name: "Test 1".to_string(),
+                collection_ms: None,
             };
             metric_tx.send(event).unwrap();
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -866,6 +1325,7 @@ mod tests {
                 metrics: create_test_metrics(50.0, Some(50.0)),
                 timestamp: Utc::now(),
                 display_name: format!("Test {i}"),
+                collection_ms: None,
             };
             let _ = metric_tx.send(event);
         }
@@ -876,4 +1336,45 @@ mod tests {
         // Actor should still be running despite lag
         // (This test mainly verifies no panic occurs)
     }
+
+    #[tokio::test]
+    async fn test_channel_health_empty_without_health_check() {
+        let (_metric_tx, metric_rx) = broadcast::channel(16);
+        let (_service_tx, service_rx) = broadcast::channel(16);
+        let handle = AlertHandle::spawn(vec![], vec![], metric_rx, service_rx);
+
+        assert!(handle.channel_health().await.is_empty());
+
+        handle.shutdown().await;
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_alert_channels_keep_distinct_non_utf8_file_sinks() {
+        use crate::config::{FileFormat, FileSink};
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let file_alert = |name: &[u8]| {
+            Some(Alert::File(FileSink {
+                path: OsStr::from_bytes(name).into(),
+                format: FileFormat::Json,
+                max_bytes: 1024,
+                max_files: 1,
+            }))
+        };
+
+        let mut config = create_test_server_config("127.0.0.1", 3000);
+        let limits = config.limits.as_mut().unwrap();
+        limits.temperature.as_mut().unwrap().alert = file_alert(b"/tmp/alerts-\xff.log");
+        limits.usage.as_mut().unwrap().alert = file_alert(b"/tmp/alerts-\xfe.log");
+
+        let (_cmd_tx, cmd_rx) = mpsc::channel(1);
+        let (_metric_tx, metric_rx) = broadcast::channel(1);
+        let (_service_tx, service_rx) = broadcast::channel(1);
+        let mut actor = AlertActor::new(cmd_rx, metric_rx, service_rx);
+        actor.register_server(config);
+
+        assert_eq!(actor.alert_channels().len(), 2);
+    }
 }
@@ -70,10 +70,7 @@ impl MetricCollectorActor {
         metric_tx: broadcast::Sender<MetricEvent>,
         polling_tx: broadcast::Sender<PollingStatusEvent>,
     ) -> Self {
-        let display_name = config
-            .display
-            .clone()
-            .unwrap_or_else(|| format!("{}:{}", config.ip, config.port));
+        let display_name = config.display.clone().unwrap_or_else(|| config.server_id());
 
         let interval_duration = Duration::from_secs(config.interval as u64);
 
@@ -157,7 +154,7 @@ impl MetricCollectorActor {
     /// Errors are logged but do not crash the actor (retry on next interval).
     #[instrument(skip(self), fields(server = %self.display_name))]
     async fn poll_metrics(&mut self) -> Result<()> {
-        let url = format!("http://{}:{}/metrics", self.config.ip, self.config.port);
+        let url = self.config.agent_url("/metrics");
         let now = Utc::now();
 
         trace!("requesting metrics from {url}");
@@ -169,7 +166,8 @@ impl MetricCollectorActor {
             request = request.header("X-MONITORING-SECRET", token);
         }
 
-        // Attempt the request
+        // Attempt the request (timed until the body is read, excluding parsing)
+        let poll_start = std::time::Instant::now();
         let poll_result = async {
             // Send request with timeout
             let response = request
@@ -187,24 +185,25 @@ impl MetricCollectorActor {
                 .text()
                 .await
                 .context("failed to read response body")?;
+            let collection_ms = poll_start.elapsed().as_millis() as u64;
 
             let metrics: ServerMetrics =
                 serde_json::from_str(&body).context("failed to parse metrics JSON")?;
 
-            Ok(metrics)
+            Ok((metrics, collection_ms))
         }
         .await;
 
         // Update polling status and emit appropriate events
         match poll_result {
-            Ok(metrics) => {
+            Ok((metrics, collection_ms)) => {
                 // Update success status
                 self.last_poll_success = Some(now);
                 self.last_poll_error = None;
 
                 // Publish polling success event
                 let polling_event = PollingStatusEvent {
-                    server_id: format!("{}:{}", self.config.ip, self.config.port),
+                    server_id: self.config.server_id(),
                     timestamp: now,
                     display_name: self.display_name.clone(),
                     success: true,
@@ -222,10 +221,11 @@ impl MetricCollectorActor {
 
                 // Create and publish metric event
                 let metric_event = MetricEvent {
-                    server_id: format!("{}:{}", self.config.ip, self.config.port),
+                    server_id: self.config.server_id(),
                     metrics,
                     timestamp: now,
                     display_name: self.display_name.clone(),
+                    collection_ms: Some(collection_ms),
                 };
 
                 match self.metric_tx.send(metric_event) {
@@ -246,7 +246,7 @@ impl MetricCollectorActor {
 
                 // Publish polling failure event
                 let polling_event = PollingStatusEvent {
-                    server_id: format!("{}:{}", self.config.ip, self.config.port),
+                    server_id: self.config.server_id(),
                     timestamp: now,
                     display_name: self.display_name.clone(),
                     success: false,
@@ -296,7 +296,7 @@ impl CollectorHandle {
     ) -> Self {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
-        let server_id = format!("{}:{}", config.ip, config.port);
+        let server_id = config.server_id();
         let display_name = config.display.clone().unwrap_or_else(|| server_id.clone());
 
         let actor = MetricCollectorActor::new(config, cmd_rx, metric_tx, polling_tx);
@@ -473,6 +473,89 @@ mod tests {
         handle.shutdown().await.unwrap();
     }
 
+    #[test]
+    fn test_ipv6_urls_are_bracketed() {
+        let config = create_test_config("::1", 3000);
+        assert_eq!(config.agent_url("/metrics"), "http://[::1]:3000/metrics");
+        assert_eq!(config.server_id(), "::1:3000");
+
+        let config = create_test_config("127.0.0.1", 3000);
+        assert_eq!(
+            config.agent_url("/metrics"),
+            "http://127.0.0.1:3000/metrics"
+        );
+        assert_eq!(config.server_id(), "127.0.0.1:3000");
+    }
+
+    #[tokio::test]
+    async fn test_poll_ipv6_agent() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Bind the mock agent to the IPv6 loopback
+        let listener = std::net::TcpListener::bind("[::1]:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mock_server = MockServer::builder().listener(listener).start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/metrics"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "system": {
+                    "name": "TestOS",
+                    "kernel_version": "5.0",
+                    "os_version": "Test",
+                    "host_name": "test-host"
+                },
+                "memory": {
+                    "total": 16000000000u64,
+                    "used": 8000000000u64,
+                    "total_swap": 4000000000u64,
+                    "used_swap": 1000000000u64
+                },
+                "cpus": {
+                    "total": 8,
+                    "arch": "x86_64",
+                    "average_usage": 12.5,
+                    "cpus": []
+                },
+                "components": {
+                    "average_temperature": null,
+                    "components": []
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let mut config = create_test_config("::1", port);
+        config.display = None;
+
+        let (metric_tx, mut metric_rx) = broadcast::channel(16);
+        let (polling_tx, mut polling_rx) = broadcast::channel(16);
+        let handle = CollectorHandle::spawn(config, metric_tx, polling_tx);
+
+        // Keys keep the unbracketed form
+        assert_eq!(handle.server_id, format!("::1:{port}"));
+
+        handle.poll_now().await.unwrap();
+
+        let event = tokio::time::timeout(tokio::time::Duration::from_millis(500), metric_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.server_id, format!("::1:{port}"));
+        assert_eq!(event.metrics.cpus.average_usage, 12.5);
+
+        let status =
+            tokio::time::timeout(tokio::time::Duration::from_millis(500), polling_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+        assert!(status.success);
+
+        handle.shutdown().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_http_404_error_handled() {
         use wiremock::matchers::{method, path};
@@ -16,8 +16,8 @@ use crate::ServerMetrics;
 
 #[cfg(feature = "storage-sqlite")]
 use crate::storage::{
-    backend::QueryRange,
-    schema::{MetricRow, ServiceCheckRow, UptimeStats},
+    backend::{DeleteCounts, QueryRange},
+    schema::{MetricRow, ServiceCheckRow, ServiceCheckRun, UptimeStats},
 };
 
 /// Event published when metrics are collected from a server
@@ -38,6 +38,9 @@ pub struct MetricEvent {
 
     /// Display name for the server (for logging/alerts)
     pub display_name: String,
+
+    /// How long polling the agent took in milliseconds (None if not polled, e.g. imports)
+    pub collection_ms: Option<u64>,
 }
 
 /// Event published when polling status changes for a server
@@ -105,6 +108,20 @@ pub enum AlertCommand {
     /// Unmute alerts
     UnmuteAlerts,
 
+    /// Get the latest health check results for all alert channels
+    GetChannelHealth {
+        respond_to: oneshot::Sender<Vec<AlertChannelHealth>>,
+    },
+
+    /// Seed a server's CPU inventory baseline (core count, architecture)
+    ///
+    /// Ignored if the server already reported metrics since startup.
+    SeedInventory {
+        server_id: String,
+        inventory: (usize, String),
+        respond_to: oneshot::Sender<()>,
+    },
+
     /// Gracefully shut down the alert actor
     Shutdown,
 }
@@ -121,10 +138,37 @@ pub struct AlertState {
     /// Temperature grace period state
     pub temp_consecutive_exceeds: usize,
 
+    /// Poll latency grace period state
+    pub latency_consecutive_exceeds: usize,
+
     /// Last metric evaluation timestamp
     pub last_evaluation: DateTime<Utc>,
 }
 
+/// Result of validating a single alert channel (Discord/webhook)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertChannelHealth {
+    /// Channel type ("discord" or "webhook")
+    pub channel: String,
+
+    /// Channel URL with the secret part masked
+    pub target: String,
+
+    /// Whether the channel is reachable and correctly configured
+    pub healthy: bool,
+
+    /// HTTP status code returned by the channel (if reachable)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+
+    /// Error description if the channel is unhealthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// When the check was performed
+    pub checked_at: DateTime<Utc>,
+}
+
 /// Commands that can be sent to the StorageActor
 #[derive(Debug)]
 pub enum StorageCommand {
@@ -153,6 +197,15 @@ pub enum StorageCommand {
         respond_to: oneshot::Sender<anyhow::Result<Vec<MetricRow>>>,
     },
 
+    /// Write metric rows straight to the backend, bypassing the event stream
+    ///
+    /// Responds with the number of rows stored.
+    #[cfg(feature = "storage-sqlite")]
+    InsertBatch {
+        rows: Vec<MetricRow>,
+        respond_to: oneshot::Sender<anyhow::Result<usize>>,
+    },
+
     /// Check backend health (Phase 2 - with persistent backend)
     #[cfg(feature = "storage-sqlite")]
     HealthCheck {
@@ -187,6 +240,15 @@ pub enum StorageCommand {
         respond_to: oneshot::Sender<anyhow::Result<UptimeStats>>,
     },
 
+    /// Query compacted service check runs within a time range (with persistent backend)
+    #[cfg(feature = "storage-sqlite")]
+    QueryServiceCheckRuns {
+        service_name: String,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        respond_to: oneshot::Sender<anyhow::Result<Vec<ServiceCheckRun>>>,
+    },
+
     /// Cleanup old service checks (Phase 3 - with persistent backend)
     #[cfg(feature = "storage-sqlite")]
     CleanupOldServiceChecks {
@@ -194,6 +256,14 @@ pub enum StorageCommand {
         respond_to: oneshot::Sender<anyhow::Result<usize>>,
     },
 
+    /// Delete all data before a cutoff (on-demand purge, with persistent backend)
+    #[cfg(feature = "storage-sqlite")]
+    DeleteBefore {
+        cutoff: DateTime<Utc>,
+        include_service_checks: bool,
+        respond_to: oneshot::Sender<anyhow::Result<DeleteCounts>>,
+    },
+
     /// Gracefully shut down the storage actor
     Shutdown,
 }
@@ -255,6 +325,12 @@ pub struct ServiceCheckEvent {
 
     /// Error message (if check failed)
     pub error_message: Option<String>,
+
+    /// Request body size in bytes (if request was sent)
+    pub request_bytes: Option<u64>,
+
+    /// Response body size in bytes (if response was received)
+    pub response_bytes: Option<u64>,
 }
 
 /// Commands that can be sent to a ServiceMonitorActor
@@ -1,4 +1,4 @@
-//! ServiceMonitorActor - Monitors HTTP/HTTPS service endpoints
+//! ServiceMonitorActor - Monitors HTTP/HTTPS and gRPC service endpoints
 //!
 //! This actor performs periodic health checks on configured services.
 //!
@@ -7,12 +7,15 @@
 //! 1. **HTTP/HTTPS support** - Can check any HTTP or HTTPS endpoint
 //! 2. **Configurable checks** - Method, status codes, body pattern matching
 //! 3. **Response time tracking** - Measures and reports response times
-//! 4. **Broadcast pattern** - Publishes ServiceCheckEvent to multiple consumers
+//! 4. **Body size tracking** - Records request/response sizes, flags oversized responses
+//! 5. **Broadcast pattern** - Publishes ServiceCheckEvent to multiple consumers
+//! 6. **Cron scheduling** - Optionally checks at cron times instead of a fixed interval
+//! 7. **gRPC health checks** - Standard `grpc.health.v1` protocol (`grpc` feature)
 //!
 //! ## Message Flow
 //!
 //! ```text
-//! Timer tick → HTTP check → Validate response → Publish ServiceCheckEvent → [AlertActor, StorageActor, ...]
+//! Timer tick / cron time → HTTP check → Validate response → Publish ServiceCheckEvent → [AlertActor, StorageActor, ...]
 //!     ↑
 //!     └─── Commands (CheckNow, UpdateInterval, Shutdown)
 //! ```
@@ -20,15 +23,42 @@
 use std::time::Duration;
 
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use cron::Schedule;
 use tokio::sync::{broadcast, mpsc, oneshot};
-use tokio::time::interval;
+use tokio::time::{interval, sleep};
 use tracing::{debug, error, instrument, trace, warn};
 
-use crate::config::{HttpMethod, ResolvedServiceConfig};
+use crate::config::{CheckType, HttpMethod, ResolvedServiceConfig};
 
 use super::messages::{ServiceCheckEvent, ServiceCommand, ServiceStatus};
 
+/// Raw HTTP response data captured by a check
+struct CheckResponse {
+    /// HTTP status code
+    status_code: u16,
+
+    /// Request body size in bytes
+    request_bytes: u64,
+
+    /// Response body (empty for HEAD requests, `None` if it exceeded
+    /// `max_body_bytes` and was not kept)
+    body: Option<String>,
+
+    /// Response body size in bytes
+    response_bytes: u64,
+}
+
+/// Delay from `now` until the next fire time of a cron schedule
+///
+/// Returns `None` if the schedule has no upcoming fire times.
+pub fn next_cron_delay(schedule: &Schedule, now: DateTime<Utc>) -> Option<Duration> {
+    schedule
+        .after(&now)
+        .next()
+        .map(|next| (next - now).to_std().unwrap_or_default())
+}
+
 /// Actor that monitors a single service endpoint
 ///
 /// Each service gets its own monitor actor. The actor runs in an infinite loop,
@@ -82,11 +112,26 @@ impl ServiceMonitorActor {
         debug!("starting service monitor actor");
 
         let mut ticker = interval(self.interval_duration);
+        let uses_cron = self.config.cron.is_some();
 
         loop {
+            // Recomputed every iteration, so commands don't shift the schedule
+            let cron_delay = self
+                .config
+                .cron
+                .as_ref()
+                .and_then(|schedule| next_cron_delay(schedule, Utc::now()));
+
             tokio::select! {
                 // Timer tick - perform health check
-                _ = ticker.tick() => {
+                _ = ticker.tick(), if !uses_cron => {
+                    if let Err(e) = self.perform_check().await {
+                        error!("health check failed: {:#}", e);
+                    }
+                }
+
+                // Cron fire time - perform health check
+                _ = sleep(cron_delay.unwrap_or_default()), if cron_delay.is_some() => {
                     if let Err(e) = self.perform_check().await {
                         error!("health check failed: {:#}", e);
                     }
@@ -102,6 +147,10 @@ impl ServiceMonitorActor {
                         }
 
                         ServiceCommand::UpdateInterval { interval_secs } => {
+                            if uses_cron {
+                                warn!("service is scheduled by cron, interval update is ignored");
+                                continue;
+                            }
                             debug!("updating interval to {interval_secs}s");
                             self.interval_duration = Duration::from_secs(interval_secs);
                             ticker = interval(self.interval_duration);
@@ -138,6 +187,21 @@ impl ServiceMonitorActor {
     async fn perform_check(&self) -> Result<()> {
         trace!("checking service at {}", self.config.url);
 
+        let event = match self.config.check {
+            CheckType::Http => self.check_http().await,
+            CheckType::Grpc => self.check_grpc().await,
+        };
+
+        // Publish event
+        if let Err(e) = self.event_tx.send(event) {
+            error!("failed to publish service check event: {}", e);
+        }
+
+        Ok(())
+    }
+
+    /// Check the service with an HTTP request
+    async fn check_http(&self) -> ServiceCheckEvent {
         let start = std::time::Instant::now();
 
         // Perform HTTP request
@@ -145,22 +209,44 @@ impl ServiceMonitorActor {
         let response_time_ms = start.elapsed().as_millis() as u64;
 
         // Create event based on result
-        let event = match check_result {
-            Ok((status_code, body)) => {
-                let status = self.evaluate_response(status_code, &body);
+        match check_result {
+            Ok(response) => {
+                let mut status =
+                    self.evaluate_response(response.status_code, response.body.as_deref());
+                let mut error_message = if status != ServiceStatus::Up {
+                    Some(format!("Unexpected status code: {}", response.status_code))
+                } else {
+                    None
+                };
+
+                // Flag unusually large responses (error pages, data leaks)
+                if let Some(max_body_bytes) = self.config.max_body_bytes
+                    && response.body.is_none()
+                {
+                    warn!(
+                        "response body exceeded limit: {} bytes (max {})",
+                        response.response_bytes, max_body_bytes
+                    );
+                    if status == ServiceStatus::Up {
+                        status = ServiceStatus::Degraded;
+                        error_message = Some(format!(
+                            "Response body exceeded limit: {} bytes (max {})",
+                            response.response_bytes, max_body_bytes
+                        ));
+                    }
+                }
+
                 ServiceCheckEvent {
                     service_name: self.config.name.clone(),
                     url: self.config.url.clone(),
                     timestamp: Utc::now(),
                     status,
                     response_time_ms: Some(response_time_ms),
-                    http_status_code: Some(status_code),
+                    http_status_code: Some(response.status_code),
                     ssl_expiry_days: None, // TODO: Implement SSL cert checking
-                    error_message: if status != ServiceStatus::Up {
-                        Some(format!("Unexpected status code: {}", status_code))
-                    } else {
-                        None
-                    },
+                    error_message,
+                    request_bytes: Some(response.request_bytes),
+                    response_bytes: Some(response.response_bytes),
                 }
             }
             Err(e) => {
@@ -174,56 +260,166 @@ impl ServiceMonitorActor {
                     http_status_code: None,
                     ssl_expiry_days: None,
                     error_message: Some(e.to_string()),
+                    request_bytes: None,
+                    response_bytes: None,
                 }
             }
+        }
+    }
+
+    /// Check the service via the gRPC Health Checking protocol
+    ///
+    /// `SERVING` maps to Up; any other health status, an error response or
+    /// an unreachable server maps to Down.
+    async fn check_grpc(&self) -> ServiceCheckEvent {
+        let start = std::time::Instant::now();
+
+        let check_result = self.execute_grpc_health_check().await;
+        let response_time_ms = start.elapsed().as_millis() as u64;
+
+        let (status, response_time_ms, error_message) = match check_result {
+            Ok(None) => (ServiceStatus::Up, Some(response_time_ms), None),
+            Ok(Some(health_status)) => (
+                ServiceStatus::Down,
+                Some(response_time_ms),
+                Some(format!("Health status: {}", health_status)),
+            ),
+            Err(e) => {
+                warn!("service check failed: {:#}", e);
+                (ServiceStatus::Down, None, Some(e.to_string()))
+            }
         };
 
-        // Publish event
-        if let Err(e) = self.event_tx.send(event) {
-            error!("failed to publish service check event: {}", e);
+        ServiceCheckEvent {
+            service_name: self.config.name.clone(),
+            url: self.config.url.clone(),
+            timestamp: Utc::now(),
+            status,
+            response_time_ms,
+            http_status_code: None,
+            ssl_expiry_days: None,
+            error_message,
+            request_bytes: None,
+            response_bytes: None,
         }
+    }
 
-        Ok(())
+    /// Call `grpc.health.v1.Health/Check`
+    ///
+    /// Returns `None` if the service is serving, otherwise the name of the
+    /// reported health status (e.g. `NOT_SERVING`).
+    #[cfg(feature = "grpc")]
+    async fn execute_grpc_health_check(&self) -> Result<Option<String>> {
+        use tonic::transport::{Endpoint, Uri};
+        use tonic_health::pb::{
+            HealthCheckRequest, health_check_response::ServingStatus, health_client::HealthClient,
+        };
+
+        let timeout = Duration::from_secs(self.config.timeout as u64);
+
+        let uri: Uri = self.config.url.parse().context("Invalid gRPC URL")?;
+        let authority = uri.authority().context("gRPC URL has no host")?;
+        let scheme = uri.scheme_str().unwrap_or("http");
+
+        // The path selects the checked service, the channel only needs the origin
+        let service = uri.path().trim_matches('/').to_string();
+        let channel = Endpoint::from_shared(format!("{scheme}://{authority}"))
+            .context("Invalid gRPC URL")?
+            .connect_timeout(timeout)
+            .timeout(timeout)
+            .connect()
+            .await
+            .context("gRPC connection failed")?;
+
+        let response = HealthClient::new(channel)
+            .check(HealthCheckRequest { service })
+            .await
+            .map_err(|status| anyhow::anyhow!("gRPC health check failed: {}", status))?;
+
+        let status =
+            ServingStatus::try_from(response.into_inner().status).unwrap_or(ServingStatus::Unknown);
+
+        Ok(match status {
+            ServingStatus::Serving => None,
+            other => Some(other.as_str_name().to_string()),
+        })
+    }
+
+    #[cfg(not(feature = "grpc"))]
+    async fn execute_grpc_health_check(&self) -> Result<Option<String>> {
+        anyhow::bail!("gRPC checks require the grpc feature")
     }
 
     /// Execute the HTTP request
     ///
-    /// Returns (status_code, body) on success
-    async fn execute_request(&self) -> Result<(u16, String)> {
+    /// Returns the status code, body and request/response body sizes on success
+    async fn execute_request(&self) -> Result<CheckResponse> {
         let method = match self.config.method {
             HttpMethod::Get => reqwest::Method::GET,
             HttpMethod::Post => reqwest::Method::POST,
             HttpMethod::Head => reqwest::Method::HEAD,
         };
 
-        let response = self
+        let request = self
             .client
             .request(method, &self.config.url)
-            .send()
+            .build()
+            .context("Invalid HTTP request")?;
+        let request_bytes = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map_or(0, |bytes| bytes.len() as u64);
+
+        let mut response = self
+            .client
+            .execute(request)
             .await
             .context("HTTP request failed")?;
 
         let status_code = response.status().as_u16();
 
-        // Get body (skip for HEAD requests)
-        let body = if matches!(self.config.method, HttpMethod::Head) {
-            String::new()
-        } else {
-            response
-                .text()
-                .await
-                .context("Failed to read response body")?
-        };
+        // Get body (skip for HEAD requests). An oversized body is not kept:
+        // its size comes from Content-Length, or from counting the rest of
+        // the stream if the header is missing
+        let max_body_bytes = self.config.max_body_bytes;
+        let mut body = Some(Vec::new());
+        let mut response_bytes = 0;
+        if !matches!(self.config.method, HttpMethod::Head) {
+            if let Some(content_length) = response.content_length()
+                && max_body_bytes.is_some_and(|max| content_length > max)
+            {
+                body = None;
+                response_bytes = content_length;
+            } else {
+                while let Some(chunk) = response
+                    .chunk()
+                    .await
+                    .context("Failed to read response body")?
+                {
+                    response_bytes += chunk.len() as u64;
+                    if max_body_bytes.is_some_and(|max| response_bytes > max) {
+                        body = None;
+                    } else if let Some(body) = body.as_mut() {
+                        body.extend_from_slice(&chunk);
+                    }
+                }
+            }
+        }
 
-        Ok((status_code, body))
+        Ok(CheckResponse {
+            status_code,
+            request_bytes,
+            body: body.map(|body| String::from_utf8_lossy(&body).into_owned()),
+            response_bytes,
+        })
     }
 
     /// Evaluate the response to determine service status
     ///
     /// Checks:
     /// 1. Status code matches expected codes (or is 2xx if not specified)
-    /// 2. Body matches pattern (if configured)
-    fn evaluate_response(&self, status_code: u16, body: &str) -> ServiceStatus {
+    /// 2. Body matches pattern (if configured and the body was kept)
+    fn evaluate_response(&self, status_code: u16, body: Option<&str>) -> ServiceStatus {
         // Check status code
         let status_ok = if let Some(ref expected) = self.config.expected_status {
             expected.contains(&status_code)
@@ -237,7 +433,9 @@ impl ServiceMonitorActor {
         }
 
         // Check body pattern if configured
-        if let Some(ref pattern) = self.config.body_pattern {
+        if let Some(ref pattern) = self.config.body_pattern
+            && let Some(body) = body
+        {
             match regex::Regex::new(pattern) {
                 Ok(re) => {
                     if !re.is_match(body) {
@@ -336,10 +534,13 @@ mod tests {
             name: "test-service".to_string(),
             url: "http://example.com".to_string(),
             interval: 60,
+            cron: None,
             timeout: 10,
+            check: CheckType::Http,
             method: HttpMethod::Get,
             expected_status: None,
             body_pattern: None,
+            max_body_bytes: None,
             grace: None,
             alert: None,
         };
@@ -352,6 +553,50 @@ mod tests {
         handle.shutdown().await;
     }
 
+    #[test]
+    fn test_next_cron_delay_business_hours() {
+        use chrono::TimeZone;
+        use std::str::FromStr;
+
+        // Weekdays at 9:00
+        let schedule = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+
+        // Friday 08:30 -> same day 09:00
+        let now = Utc.with_ymd_and_hms(2025, 1, 3, 8, 30, 0).unwrap();
+        assert_eq!(
+            next_cron_delay(&schedule, now),
+            Some(Duration::from_secs(30 * 60))
+        );
+
+        // Friday 10:00 -> Monday 09:00
+        let now = Utc.with_ymd_and_hms(2025, 1, 3, 10, 0, 0).unwrap();
+        let next = schedule.after(&now).next().unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap());
+        assert_eq!(
+            next_cron_delay(&schedule, now),
+            Some(Duration::from_secs((2 * 24 + 23) * 3600))
+        );
+
+        // Exactly at a fire time -> the next one
+        let now = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+        assert_eq!(
+            next_cron_delay(&schedule, now),
+            Some(Duration::from_secs(24 * 3600))
+        );
+    }
+
+    #[test]
+    fn test_next_cron_delay_without_upcoming_times() {
+        use chrono::TimeZone;
+        use std::str::FromStr;
+
+        // Only in 2020
+        let schedule = Schedule::from_str("0 0 0 1 1 * 2020").unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(next_cron_delay(&schedule, now), None);
+    }
+
     #[tokio::test]
     async fn test_update_interval() {
         let (event_tx, _) = broadcast::channel(16);
@@ -360,10 +605,13 @@ mod tests {
             name: "test-service".to_string(),
             url: "http://example.com".to_string(),
             interval: 60,
+            cron: None,
             timeout: 10,
+            check: CheckType::Http,
             method: HttpMethod::Get,
             expected_status: None,
             body_pattern: None,
+            max_body_bytes: None,
             grace: None,
             alert: None,
         };
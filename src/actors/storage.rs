@@ -92,6 +92,10 @@ pub struct StorageActor {
     #[cfg(feature = "storage-sqlite")]
     cleanup_interval_hours: Option<u32>,
 
+    /// Service check compaction interval in hours (None = disabled)
+    #[cfg(feature = "storage-sqlite")]
+    compaction_interval_hours: Option<u32>,
+
     /// Cleanup statistics (Phase 4)
     #[cfg(feature = "storage-sqlite")]
     last_cleanup_time: Option<chrono::DateTime<chrono::Utc>>,
@@ -113,6 +117,7 @@ impl StorageActor {
         backend: Option<Box<dyn StorageBackend>>,
         retention_days: Option<u32>,
         cleanup_interval_hours: Option<u32>,
+        compaction_interval_hours: Option<u32>,
     ) -> Self {
         let mode = if backend.is_some() {
             "persistent"
@@ -129,6 +134,10 @@ impl StorageActor {
             debug!("cleanup interval: every {} hours", hours);
         }
 
+        if let Some(hours) = compaction_interval_hours {
+            debug!("service check compaction enabled: every {} hours", hours);
+        }
+
         Self {
             backend,
             batch_buffer: Vec::with_capacity(BATCH_SIZE_TRIGGER),
@@ -140,6 +149,7 @@ impl StorageActor {
             flush_count: 0,
             retention_days,
             cleanup_interval_hours,
+            compaction_interval_hours,
             last_cleanup_time: None,
             total_metrics_deleted: 0,
             total_service_checks_deleted: 0,
@@ -201,6 +211,15 @@ impl StorageActor {
         #[cfg(feature = "storage-sqlite")]
         let mut cleanup_interval = time::interval(cleanup_duration);
 
+        // Compaction interval for service checks (disabled unless configured)
+        #[cfg(feature = "storage-sqlite")]
+        let has_compaction = self.compaction_interval_hours.is_some();
+
+        #[cfg(feature = "storage-sqlite")]
+        let mut compaction_interval = time::interval(Duration::from_secs(
+            self.compaction_interval_hours.unwrap_or(1).max(1) as u64 * 3600,
+        ));
+
         // Run initial cleanup on startup if retention is configured
         #[cfg(feature = "storage-sqlite")]
         if has_backend && has_retention {
@@ -262,6 +281,12 @@ impl StorageActor {
                         self.run_cleanup().await;
                     }
 
+                    // Compaction trigger for service check runs
+                    _ = compaction_interval.tick(), if has_backend && has_compaction => {
+                        debug!("service check compaction triggered");
+                        self.run_compaction().await;
+                    }
+
                     // Handle commands
                     Some(cmd) = self.command_rx.recv() => {
                         self.handle_command(cmd).await;
@@ -371,12 +396,13 @@ impl StorageActor {
         // If we have a persistent backend, add to batch buffer
         #[cfg(feature = "storage-sqlite")]
         if self.backend.is_some() {
-            let row = MetricRow::from_server_metrics(
+            let mut row = MetricRow::from_server_metrics(
                 event.server_id.clone(),
                 event.display_name.clone(),
                 event.timestamp,
                 &event.metrics,
             );
+            row.collection_ms = event.collection_ms;
 
             self.batch_buffer.push(row);
 
@@ -540,6 +566,32 @@ impl StorageActor {
         }
     }
 
+    /// Run compaction - collapse service check runs up to now
+    #[cfg(feature = "storage-sqlite")]
+    async fn run_compaction(&mut self) {
+        // Pending checks must be persisted first, compaction skips anything
+        // older than its watermark afterwards
+        self.flush_service_checks_batch().await;
+
+        if let Some(backend) = self.backend.as_ref() {
+            match backend.compact_service_checks(chrono::Utc::now()).await {
+                Ok(compacted) if compacted > 0 => {
+                    info!(
+                        "compaction complete: compacted {} service checks",
+                        compacted
+                    );
+                }
+                Ok(_) => {
+                    trace!("compaction: no new service checks to compact");
+                }
+                Err(e) => {
+                    error!("failed to compact service checks: {}", e);
+                    // Don't crash the actor - compaction will be retried on next interval
+                }
+            }
+        }
+    }
+
     /// Handle a command
     async fn handle_command(&mut self, cmd: StorageCommand) {
         match cmd {
@@ -598,6 +650,22 @@ impl StorageActor {
                 let _ = respond_to.send(result);
             }
 
+            #[cfg(feature = "storage-sqlite")]
+            StorageCommand::InsertBatch { rows, respond_to } => {
+                let count = rows.len();
+                let result = match self.backend.as_ref() {
+                    Some(backend) => backend
+                        .insert_batch(rows)
+                        .await
+                        .map(|_| count)
+                        .map_err(Into::into),
+                    None => Err(anyhow::anyhow!(
+                        "Insert operations not available in in-memory mode"
+                    )),
+                };
+                let _ = respond_to.send(result);
+            }
+
             #[cfg(feature = "storage-sqlite")]
             StorageCommand::HealthCheck { respond_to } => {
                 let result = match self.backend.as_ref() {
@@ -669,6 +737,25 @@ impl StorageActor {
                 let _ = respond_to.send(result);
             }
 
+            #[cfg(feature = "storage-sqlite")]
+            StorageCommand::QueryServiceCheckRuns {
+                service_name,
+                start,
+                end,
+                respond_to,
+            } => {
+                let result = match self.backend.as_ref() {
+                    Some(backend) => backend
+                        .query_service_check_runs(&service_name, start, end)
+                        .await
+                        .map_err(Into::into),
+                    None => Err(anyhow::anyhow!(
+                        "Query operations not available in in-memory mode"
+                    )),
+                };
+                let _ = respond_to.send(result);
+            }
+
             #[cfg(feature = "storage-sqlite")]
             StorageCommand::CleanupOldServiceChecks { before, respond_to } => {
                 let result = match self.backend.as_ref() {
@@ -683,6 +770,28 @@ impl StorageActor {
                 let _ = respond_to.send(result);
             }
 
+            #[cfg(feature = "storage-sqlite")]
+            StorageCommand::DeleteBefore {
+                cutoff,
+                include_service_checks,
+                respond_to,
+            } => {
+                // Flush first so buffered rows before the cutoff are deleted too
+                self.flush_batch().await;
+                self.flush_service_checks_batch().await;
+
+                let result = match self.backend.as_ref() {
+                    Some(backend) => backend
+                        .delete_before(cutoff, include_service_checks)
+                        .await
+                        .map_err(Into::into),
+                    None => Err(anyhow::anyhow!(
+                        "Delete operations not available in in-memory mode"
+                    )),
+                };
+                let _ = respond_to.send(result);
+            }
+
             StorageCommand::Shutdown => {
                 debug!("received shutdown command");
                 // The loop will break and handle cleanup
@@ -743,6 +852,7 @@ impl StorageHandle {
         backend: Option<Box<dyn StorageBackend>>,
         retention_days: Option<u32>,
         cleanup_interval_hours: Option<u32>,
+        compaction_interval_hours: Option<u32>,
     ) -> Self {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
@@ -753,6 +863,7 @@ impl StorageHandle {
             backend,
             retention_days,
             cleanup_interval_hours,
+            compaction_interval_hours,
         );
 
         tokio::spawn(actor.run());
@@ -768,7 +879,7 @@ impl StorageHandle {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
 
         #[cfg(feature = "storage-sqlite")]
-        let actor = StorageActor::new(cmd_rx, metric_rx, service_check_rx, None, None, None);
+        let actor = StorageActor::new(cmd_rx, metric_rx, service_check_rx, None, None, None, None);
 
         #[cfg(not(feature = "storage-sqlite"))]
         let actor = StorageActor::new(cmd_rx, metric_rx, service_check_rx);
@@ -833,6 +944,23 @@ impl StorageHandle {
         rx.await?
     }
 
+    /// Write metric rows straight to the backend (requires persistent backend)
+    ///
+    /// Unlike events on the metric channel, rows can't be dropped by a lagging
+    /// actor. Returns the number of rows stored.
+    #[cfg(feature = "storage-sqlite")]
+    pub async fn insert_batch(&self, rows: Vec<MetricRow>) -> anyhow::Result<usize> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(StorageCommand::InsertBatch {
+                rows,
+                respond_to: tx,
+            })
+            .await?;
+
+        rx.await?
+    }
+
     /// Check backend health
     #[cfg(feature = "storage-sqlite")]
     pub async fn health_check(&self) -> anyhow::Result<String> {
@@ -907,6 +1035,46 @@ impl StorageHandle {
         rx.await?
     }
 
+    /// Query compacted service check runs within a time range (requires persistent backend)
+    #[cfg(feature = "storage-sqlite")]
+    pub async fn query_service_check_runs(
+        &self,
+        service_name: String,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<crate::storage::schema::ServiceCheckRun>> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(StorageCommand::QueryServiceCheckRuns {
+                service_name,
+                start,
+                end,
+                respond_to: tx,
+            })
+            .await?;
+
+        rx.await?
+    }
+
+    /// Delete all metrics (and optionally service checks) before a cutoff (requires persistent backend)
+    #[cfg(feature = "storage-sqlite")]
+    pub async fn delete_before(
+        &self,
+        cutoff: chrono::DateTime<chrono::Utc>,
+        include_service_checks: bool,
+    ) -> anyhow::Result<crate::storage::backend::DeleteCounts> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(StorageCommand::DeleteBefore {
+                cutoff,
+                include_service_checks,
+                respond_to: tx,
+            })
+            .await?;
+
+        rx.await?
+    }
+
     /// Shutdown the storage actor
     pub async fn shutdown(&self) {
         let _ = self.sender.send(StorageCommand::Shutdown).await;
@@ -931,6 +1099,7 @@ mod tests {
             metrics: ServerMetrics::default(),
             timestamp: Utc::now(),
             display_name: "Test Server".to_string(),
+            collection_ms: None,
         };
 
         metric_tx.send(event).unwrap();
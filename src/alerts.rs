@@ -1,11 +1,15 @@
+use std::path::Path;
+use std::time::Duration;
+
 use chrono::Utc;
-use reqwest::Client;
+use reqwest::{Client, Url};
 use serde_json::json;
 use tracing::{error, info, instrument};
 
-use crate::actors::messages::ServiceStatus;
-use crate::config::{Alert, ResolvedServerConfig, Webhook};
-use crate::discord::{DiscordManager, MessageBuilder};
+use crate::actors::messages::{AlertChannelHealth, ServiceStatus};
+use crate::config::{Alert, FileSink, ResolvedServerConfig, Webhook};
+use crate::discord::{DiscordManager, Embed, MessageBuilder};
+use crate::file_sink;
 use crate::monitors::resources::ResourceEvaluation;
 
 #[derive(Debug, Clone)]
@@ -28,7 +32,7 @@ impl AlertManager {
         self.server_config
             .display
             .clone()
-            .unwrap_or_else(|| format!("{}:{}", self.server_config.ip, self.server_config.port))
+            .unwrap_or_else(|| self.server_config.server_id())
     }
 
     #[instrument(skip(self))]
@@ -45,32 +49,13 @@ impl AlertManager {
             return;
         };
 
-        match alert_config {
-            Alert::Discord(discord) => {
-                let embed = self.discord_manager.build_temperature_embed(
-                    evaluation,
-                    temperature,
-                    temp_limit.limit,
-                );
-                let mut message_builder = MessageBuilder::new().add_embed(embed);
-                if let Some(user_id) = &discord.user_id {
-                    message_builder = message_builder.content(format!(
-                        "🌡️ ({} ~ {:.1}°C) <@{user_id}>",
-                        self.server_display(),
-                        temperature
-                    ));
-                }
-
-                self.discord_manager
-                    .send_message(discord, &message_builder.build())
-                    .await;
-            }
-            Alert::Webhook(webhook) => {
-                let message =
-                    self.format_temperature_message(evaluation, temperature, temp_limit.limit);
-                self.send_webhook_alert(webhook, &message).await;
-            }
-        }
+        let embed =
+            self.discord_manager
+                .build_temperature_embed(evaluation, temperature, temp_limit.limit);
+        let mention = format!("🌡️ ({} ~ {:.1}°C)", self.server_display(), temperature);
+        let message = self.format_temperature_message(evaluation, temperature, temp_limit.limit);
+        self.dispatch_server_alert(alert_config, "temperature", embed, mention, &message)
+            .await;
     }
 
     #[instrument(skip(self))]
@@ -87,27 +72,97 @@ impl AlertManager {
             return;
         };
 
+        let embed = self
+            .discord_manager
+            .build_usage_embed(evaluation, usage, usage_limit.limit);
+        let mention = format!("💻 ({} ~ {:.1}%)", self.server_display(), usage);
+        let message = self.format_usage_message(evaluation, usage, usage_limit.limit);
+        self.dispatch_server_alert(alert_config, "usage", embed, mention, &message)
+            .await;
+    }
+
+    #[instrument(skip(self))]
+    pub async fn send_latency_alert(
+        &self,
+        evaluation: ResourceEvaluation,
+        collection_ms: u64,
+        trending: bool,
+    ) {
+        let Some(limits) = &self.server_config.limits else {
+            return;
+        };
+
+        let Some(latency_limit) = &limits.latency else {
+            return;
+        };
+
+        let Some(alert_config) = &latency_limit.alert else {
+            return;
+        };
+
+        let reason = match (trending, latency_limit.limit) {
+            (true, _) => format!(
+                "grew over the last {} polls",
+                latency_limit.trend.unwrap_or_default()
+            ),
+            (false, Some(limit)) => format!("limit: {}ms", limit),
+            (false, None) => String::new(),
+        };
+
+        let embed = self
+            .discord_manager
+            .build_latency_embed(evaluation, collection_ms, &reason);
+        let mention = format!("🐢 ({} ~ {}ms)", self.server_display(), collection_ms);
+        let message = self.format_latency_message(evaluation, collection_ms, &reason);
+        self.dispatch_server_alert(alert_config, "latency", embed, mention, &message)
+            .await;
+    }
+
+    #[instrument(skip(self))]
+    pub async fn send_inventory_alert(&self, changes: &[String]) {
+        let Some(limits) = &self.server_config.limits else {
+            return;
+        };
+
+        let Some(inventory_limit) = &limits.inventory else {
+            return;
+        };
+
+        let Some(alert_config) = &inventory_limit.alert else {
+            return;
+        };
+
+        let embed = self.discord_manager.build_inventory_embed(changes);
+        let mention = format!("🧩 ({} ~ {})", self.server_display(), changes.join(", "));
+        let message = self.format_inventory_message(changes);
+        self.dispatch_server_alert(alert_config, "inventory", embed, mention, &message)
+            .await;
+    }
+
+    /// Deliver a server alert to its configured channel
+    ///
+    /// Discord gets the embed, with `mention` as the content line if a user is
+    /// configured to be pinged; webhooks and file sinks get the plain `message`.
+    async fn dispatch_server_alert(
+        &self,
+        alert_config: &Alert,
+        kind: &str,
+        embed: Embed,
+        mention: String,
+        message: &str,
+    ) {
         match alert_config {
             Alert::Discord(discord) => {
-                let embed =
-                    self.discord_manager
-                        .build_usage_embed(evaluation, usage, usage_limit.limit);
                 let mut message_builder = MessageBuilder::new().add_embed(embed);
                 if let Some(user_id) = &discord.user_id {
-                    message_builder = message_builder.content(format!(
-                        "💻 ({} ~ {:.1}%) <@{user_id}>",
-                        self.server_display(),
-                        usage
-                    ));
+                    message_builder = message_builder.content(format!("{mention} <@{user_id}>"));
                 }
                 self.discord_manager
                     .send_message(discord, &message_builder.build())
                     .await;
             }
-            Alert::Webhook(webhook) => {
-                let message = self.format_usage_message(evaluation, usage, usage_limit.limit);
-                self.send_webhook_alert(webhook, &message).await;
-            }
+            Alert::Webhook(webhook) => self.send_webhook_alert(webhook, message).await,
+            Alert::File(sink) => self.send_file_alert(sink, kind, message).await,
         }
     }
 
@@ -162,6 +217,53 @@ impl AlertManager {
         }
     }
 
+    fn format_latency_message(
+        &self,
+        evaluation: ResourceEvaluation,
+        collection_ms: u64,
+        reason: &str,
+    ) -> String {
+        let server = self.server_display();
+        match evaluation {
+            ResourceEvaluation::StartsToExceed => {
+                format!(
+                    "🐢 **Poll Latency Alert**: Polling server `{}` took **{}ms** ({})",
+                    server, collection_ms, reason
+                )
+            }
+            ResourceEvaluation::BackToOk => {
+                format!(
+                    "✅ **Poll Latency OK**: Polling server `{}` is back to normal: **{}ms**",
+                    server, collection_ms
+                )
+            }
+            _ => format!(
+                "Poll latency update for server `{}`: {}ms",
+                server, collection_ms
+            ),
+        }
+    }
+
+    fn format_inventory_message(&self, changes: &[String]) -> String {
+        format!(
+            "🧩 **CPU Inventory Alert**: Server `{}` changed unexpectedly: {}",
+            self.server_display(),
+            changes.join(", ")
+        )
+    }
+
+    /// Append a server alert record to a file sink
+    async fn send_file_alert(&self, sink: &FileSink, kind: &str, message: &str) {
+        let record = json!({
+            "timestamp": Utc::now().to_rfc3339(),
+            "kind": kind,
+            "server": self.server_display(),
+            "message": message,
+        });
+
+        file_sink::send_record(sink, &record).await;
+    }
+
     #[instrument(skip(self, webhook))]
     async fn send_webhook_alert(&self, webhook: &Webhook, message: &str) {
         let payload = json!({
@@ -248,45 +350,8 @@ impl AlertManager {
                     .await;
             }
             Alert::Webhook(webhook) => {
-                let message = match current_status {
-                    ServiceStatus::Down | ServiceStatus::Degraded => {
-                        let status_text = if current_status == ServiceStatus::Down {
-                            "DOWN"
-                        } else {
-                            "DEGRADED"
-                        };
-                        if let Some(err) = error_message {
-                            format!(
-                                "🔴 **Service {}**: `{}` is {} ({})\nURL: {}",
-                                status_text, service_name, status_text, err, url
-                            )
-                        } else {
-                            format!(
-                                "🔴 **Service {}**: `{}` is {}\nURL: {}",
-                                status_text, service_name, status_text, url
-                            )
-                        }
-                    }
-                    ServiceStatus::Up => {
-                        format!(
-                            "✅ **Service Recovered**: `{}` is back UP\nURL: {}",
-                            service_name, url
-                        )
-                    }
-                };
-
-                let payload = json!({
-                    "message": message,
-                    "service": service_name,
-                    "url": url,
-                    "status": match current_status {
-                        ServiceStatus::Up => "up",
-                        ServiceStatus::Down => "down",
-                        ServiceStatus::Degraded => "degraded",
-                    },
-                    "error": error_message,
-                    "timestamp": Utc::now().to_rfc3339()
-                });
+                let payload =
+                    Self::service_payload(service_name, url, current_status, error_message);
 
                 match self.client.post(&webhook.url).json(&payload).send().await {
                     Ok(response) => {
@@ -304,6 +369,299 @@ impl AlertManager {
                     }
                 }
             }
+            Alert::File(sink) => {
+                let mut record =
+                    Self::service_payload(service_name, url, current_status, error_message);
+                record["kind"] = json!("service");
+                file_sink::send_record(sink, &record).await;
+            }
         }
     }
+
+    /// Payload describing a service status change (webhook body, file record)
+    fn service_payload(
+        service_name: &str,
+        url: &str,
+        current_status: ServiceStatus,
+        error_message: Option<&str>,
+    ) -> serde_json::Value {
+        let message = match current_status {
+            ServiceStatus::Down | ServiceStatus::Degraded => {
+                let status_text = if current_status == ServiceStatus::Down {
+                    "DOWN"
+                } else {
+                    "DEGRADED"
+                };
+                if let Some(err) = error_message {
+                    format!(
+                        "🔴 **Service {}**: `{}` is {} ({})\nURL: {}",
+                        status_text, service_name, status_text, err, url
+                    )
+                } else {
+                    format!(
+                        "🔴 **Service {}**: `{}` is {}\nURL: {}",
+                        status_text, service_name, status_text, url
+                    )
+                }
+            }
+            ServiceStatus::Up => {
+                format!(
+                    "✅ **Service Recovered**: `{}` is back UP\nURL: {}",
+                    service_name, url
+                )
+            }
+        };
+
+        json!({
+            "message": message,
+            "service": service_name,
+            "url": url,
+            "status": match current_status {
+                ServiceStatus::Up => "up",
+                ServiceStatus::Down => "down",
+                ServiceStatus::Degraded => "degraded",
+            },
+            "error": error_message,
+            "timestamp": Utc::now().to_rfc3339()
+        })
+    }
+}
+
+/// Validate an alert channel without sending a notification
+///
+/// - Discord: `GET` on the webhook URL, which returns the webhook object for
+///   valid webhooks and 401/404 for deleted or mistyped ones
+/// - Webhook: `HEAD` on the URL; the channel is unhealthy if it is unreachable
+///   or answers with anything but a 2xx status
+/// - File: the file (or, before the first alert, its directory) is writable
+#[instrument(skip(client, alert))]
+pub async fn check_alert_channel(
+    client: &Client,
+    alert: &Alert,
+    timeout: Duration,
+) -> AlertChannelHealth {
+    let (channel, url, request) = match alert {
+        Alert::Discord(discord) => ("discord", &discord.url, client.get(&discord.url)),
+        Alert::Webhook(webhook) => ("webhook", &webhook.url, client.head(&webhook.url)),
+        Alert::File(sink) => return check_file_sink(sink).await,
+    };
+
+    let (healthy, status_code, error) = match request.timeout(timeout).send().await {
+        Ok(response) => {
+            // Anything but a success (e.g. a webhook refusing HEAD with 405, or
+            // rejecting us with 401) means deliveries are unlikely to land
+            let status = response.status();
+            let healthy = status.is_success();
+            let error = (!healthy).then(|| format!("unexpected status: {}", status));
+            (healthy, Some(status.as_u16()), error)
+        }
+        Err(e) => (false, None, Some(e.to_string())),
+    };
+
+    AlertChannelHealth {
+        channel: channel.to_string(),
+        target: redact_url(url),
+        healthy,
+        status_code,
+        error,
+        checked_at: Utc::now(),
+    }
+}
+
+/// Check that a file sink can be appended to, without creating the file
+///
+/// An existing file must open for appending; a missing one only needs a
+/// writable parent directory, since the first alert creates it. Only the file
+/// name is reported, the directory layout stays private.
+async fn check_file_sink(sink: &FileSink) -> AlertChannelHealth {
+    let result = match tokio::fs::try_exists(&sink.path).await {
+        Ok(true) => tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(&sink.path)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+        Ok(false) => {
+            let parent = sink
+                .path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            match tokio::fs::metadata(parent).await {
+                Ok(metadata) if !metadata.is_dir() => Err("parent is not a directory".to_string()),
+                Ok(metadata) if metadata.permissions().readonly() => {
+                    Err("parent directory is read-only".to_string())
+                }
+                Ok(_) => Ok(()),
+                Err(e) => Err(format!("parent directory: {e}")),
+            }
+        }
+        Err(e) => Err(e.to_string()),
+    };
+
+    let target = sink.path.file_name().map_or_else(
+        || "<invalid path>".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+
+    AlertChannelHealth {
+        channel: "file".to_string(),
+        target,
+        healthy: result.is_ok(),
+        status_code: None,
+        error: result.err(),
+        checked_at: Utc::now(),
+    }
+}
+
+/// Mask the last path segment and query of a URL (where webhook tokens live)
+fn redact_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url) else {
+        return "<invalid url>".to_string();
+    };
+
+    let segments: Vec<String> = parsed
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some((_, rest)) = segments.split_last() {
+        let mut path = rest.join("/");
+        path.push_str("/***");
+        parsed.set_path(&path);
+    }
+    parsed.set_query(None);
+
+    parsed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_sink_appends_json_records() {
+        use crate::config::{FileFormat, ResolvedLimit, ResolvedLimits};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("alerts.jsonl");
+        let alert = Alert::File(FileSink {
+            path: path.clone(),
+            format: FileFormat::Json,
+            max_bytes: 1024 * 1024,
+            max_files: 1,
+        });
+
+        let manager = AlertManager::new(ResolvedServerConfig {
+            ip: "10.0.0.1".parse().unwrap(),
+            port: 3000,
+            interval: 5,
+            token: None,
+            display: Some("web-01".to_string()),
+            limits: Some(ResolvedLimits {
+                temperature: Some(ResolvedLimit {
+                    limit: 70,
+                    grace: None,
+                    alert: Some(alert.clone()),
+                }),
+                usage: None,
+                latency: None,
+                inventory: None,
+            }),
+        });
+
+        manager
+            .send_temperature_alert(ResourceEvaluation::StartsToExceed, 75.5)
+            .await;
+        manager
+            .send_service_alert(
+                &alert,
+                "api",
+                "https://api.example.com/health",
+                Some(ServiceStatus::Up),
+                ServiceStatus::Down,
+                Some("connection refused"),
+            )
+            .await;
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+
+        let temperature = &records[0];
+        assert_eq!(temperature["kind"], "temperature");
+        assert_eq!(temperature["server"], "web-01");
+        assert!(
+            temperature["message"]
+                .as_str()
+                .unwrap()
+                .contains("**75.5°C**")
+        );
+        assert!(
+            chrono::DateTime::parse_from_rfc3339(temperature["timestamp"].as_str().unwrap())
+                .is_ok()
+        );
+
+        let service = &records[1];
+        assert_eq!(service["kind"], "service");
+        assert_eq!(service["service"], "api");
+        assert_eq!(service["status"], "down");
+        assert_eq!(service["error"], "connection refused");
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_health_check_has_no_side_effects() {
+        use crate::config::FileFormat;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_alert = |path: std::path::PathBuf| {
+            Alert::File(FileSink {
+                path,
+                format: FileFormat::Json,
+                max_bytes: 1024,
+                max_files: 1,
+            })
+        };
+        let client = Client::new();
+        let timeout = Duration::from_secs(1);
+
+        // A missing file in a writable directory is healthy, but not created
+        let path = temp_dir.path().join("alerts.jsonl");
+        let health = check_alert_channel(&client, &file_alert(path.clone()), timeout).await;
+        assert!(health.healthy, "unexpected error: {:?}", health.error);
+        assert_eq!(health.target, "alerts.jsonl");
+        assert!(!path.exists());
+
+        // A missing directory is reported without exposing the path
+        let path = temp_dir.path().join("missing").join("alerts.jsonl");
+        let health = check_alert_channel(&client, &file_alert(path), timeout).await;
+        assert!(!health.healthy);
+        assert_eq!(health.target, "alerts.jsonl");
+        let dir = temp_dir.path().to_string_lossy().into_owned();
+        assert!(!health.error.unwrap().contains(&dir));
+    }
+
+    #[test]
+    fn test_redact_url_masks_token() {
+        assert_eq!(
+            redact_url("https://discord.com/api/webhooks/123/secret-token"),
+            "https://discord.com/api/webhooks/123/***"
+        );
+        assert_eq!(
+            redact_url("https://hooks.example.com/notify?token=abc"),
+            "https://hooks.example.com/***"
+        );
+        assert_eq!(
+            redact_url("http://127.0.0.1:9000"),
+            "http://127.0.0.1:9000/"
+        );
+    }
 }
@@ -14,8 +14,11 @@
 //!
 //! - `GET /api/v1/health` - Health check
 //! - `GET /api/v1/stats` - System statistics
+//! - `DELETE /api/v1/metrics?before=...&confirm=true` - Bulk deletion (requires auth token)
 //! - `GET /api/v1/servers` - List monitored servers
 //! - `GET /api/v1/servers/{id}/metrics` - Server metrics
+//! - `POST /api/v1/servers/{id}/import` - Import (backfill) server metrics
+//! - `GET /api/v1/servers/{id}/system-history` - System information history
 //! - `GET /api/v1/services` - List monitored services
 //! - `GET /api/v1/services/{name}/uptime` - Service uptime
 //! - `WS /api/v1/stream` - Real-time metric streaming
@@ -41,12 +44,17 @@ pub use error::{ApiError, ApiResult};
 pub use state::ApiState;
 #[cfg(feature = "api")]
 pub use types::{
-    HealthResponse, LatestMetricsResponse, MetricsResponse, ServerInfo, ServersResponse,
-    ServiceChecksResponse, ServiceInfo, ServicesResponse, StatsResponse, UptimeResponse,
+    DeleteMetricsResponse, HealthResponse, ImportRequest, ImportResponse, ImportSample,
+    LatestMetricsResponse, MetricsResponse, ServerInfo, ServersResponse, ServiceChecksResponse,
+    ServiceInfo, ServicesResponse, StatsResponse, SystemHistoryResponse, SystemSnapshot,
+    UptimeResponse,
 };
 
 #[cfg(feature = "api")]
-use axum::{Router, routing::get};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
 use std::net::SocketAddr;
 use tracing::info;
 
@@ -85,7 +93,7 @@ pub async fn spawn_api_server(config: ApiConfig, state: ApiState) -> anyhow::Res
     info!("starting API server on {}", config.bind_addr);
 
     // Build router with all routes
-    let mut app = Router::new()
+    let mut router = Router::new()
         .route("/api/v1/health", get(routes::health::health_check))
         .route("/api/v1/stats", get(routes::stats::get_stats))
         .route("/api/v1/servers", get(routes::servers::list_servers))
@@ -97,6 +105,10 @@ pub async fn spawn_api_server(config: ApiConfig, state: ApiState) -> anyhow::Res
             "/api/v1/servers/:id/metrics/latest",
             get(routes::servers::get_latest_metrics),
         )
+        .route(
+            "/api/v1/servers/:id/system-history",
+            get(routes::servers::get_system_history),
+        )
         .route("/api/v1/services", get(routes::services::list_services))
         .route(
             "/api/v1/services/:name/checks",
@@ -106,9 +118,21 @@ pub async fn spawn_api_server(config: ApiConfig, state: ApiState) -> anyhow::Res
             "/api/v1/services/:name/uptime",
             get(routes::services::get_uptime),
         )
-        .route("/api/v1/stream", get(websocket::websocket_handler))
-        .with_state(state)
-        .layer(TraceLayer::new_for_http());
+        .route("/api/v1/stream", get(websocket::websocket_handler));
+
+    // Bulk deletion and imports are only exposed behind authentication
+    if config.auth_token.is_some() {
+        router = router
+            .route("/api/v1/metrics", delete(routes::metrics::delete_metrics))
+            .route(
+                "/api/v1/servers/:id/import",
+                post(routes::servers::import_metrics),
+            );
+    } else {
+        info!("bulk deletion and import endpoints disabled (no auth token configured)");
+    }
+
+    let mut app = router.with_state(state).layer(TraceLayer::new_for_http());
 
     // Add web dashboard static files if feature enabled
     #[cfg(feature = "web-dashboard")]
@@ -1,14 +1,23 @@
 //! Health check endpoint
 
-use crate::api::types::HealthResponse;
-use axum::Json;
+use crate::api::{state::ApiState, types::HealthResponse};
+use axum::{Json, extract::State};
 
 /// GET /api/v1/health
 ///
-/// Returns a simple health check response
-pub async fn health_check() -> Json<HealthResponse> {
+/// Returns a health check response including the latest alert channel checks.
+/// Status is "degraded" if any alert channel is unreachable or misconfigured.
+pub async fn health_check(State(state): State<ApiState>) -> Json<HealthResponse> {
+    let alert_channels = state.alerts.channel_health().await;
+    let status = if alert_channels.iter().all(|channel| channel.healthy) {
+        "ok"
+    } else {
+        "degraded"
+    };
+
     Json(HealthResponse {
-        status: "ok".to_string(),
+        status: status.to_string(),
         timestamp: chrono::Utc::now().to_rfc3339(),
+        alert_channels,
     })
 }
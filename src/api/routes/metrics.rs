@@ -0,0 +1,56 @@
+//! Bulk metric deletion endpoint
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::api::{
+    error::{ApiError, ApiResult},
+    state::ApiState,
+    types::DeleteMetricsResponse,
+};
+
+/// Query parameters for bulk deletion
+#[derive(Debug, Deserialize)]
+pub struct DeleteMetricsQuery {
+    /// Delete everything before this time (ISO 8601 format, required)
+    before: DateTime<Utc>,
+
+    /// Must be `true` to perform the deletion
+    #[serde(default)]
+    confirm: bool,
+
+    /// Also delete service checks (default: false)
+    #[serde(default)]
+    include_service_checks: bool,
+}
+
+/// DELETE /api/v1/metrics
+///
+/// Delete all metrics across all servers (and optionally service checks)
+/// recorded before the given timestamp. Only registered when an API auth
+/// token is configured, and requires `confirm=true`.
+pub async fn delete_metrics(
+    State(state): State<ApiState>,
+    Query(query): Query<DeleteMetricsQuery>,
+) -> ApiResult<Json<DeleteMetricsResponse>> {
+    if !query.confirm {
+        return Err(ApiError::InvalidRequest(
+            "Bulk deletion is irreversible, pass confirm=true to proceed".to_string(),
+        ));
+    }
+
+    let counts = state
+        .storage
+        .delete_before(query.before, query.include_service_checks)
+        .await?;
+
+    Ok(Json(DeleteMetricsResponse {
+        before: query.before.to_rfc3339(),
+        metrics_deleted: counts.metrics,
+        service_checks_deleted: counts.service_checks,
+    }))
+}
@@ -1,6 +1,7 @@
 //! API route handlers
 
 pub mod health;
+pub mod metrics;
 pub mod servers;
 pub mod services;
 pub mod stats;
@@ -8,15 +8,20 @@ use chrono::{DateTime, Duration, Utc};
 use serde::Deserialize;
 
 use crate::{
+    actors::messages::MetricEvent,
     api::{
-        error::ApiResult,
+        error::{ApiError, ApiResult},
         state::ApiState,
         types::{
-            LatestMetricsResponse, MetricsResponse, MonitoringStatus, ServerInfo, ServersResponse,
+            ImportRequest, ImportResponse, LatestMetricsResponse, MetricsResponse,
+            MonitoringStatus, ServerInfo, ServersResponse, SystemHistoryResponse, SystemSnapshot,
         },
         utils::determine_server_health,
     },
-    storage::backend::QueryRange,
+    storage::{
+        backend::{QueryRange, RangePager},
+        schema::MetricRow,
+    },
 };
 
 /// Default limit for metrics query
@@ -50,6 +55,26 @@ pub struct LatestQuery {
     limit: Option<usize>,
 }
 
+/// Maximum number of samples per import request
+///
+/// Historical samples are written to storage in a single transaction and live
+/// samples are published one by one on the metric broadcast channel, so larger
+/// backfills are sent in chunks to keep both bounded.
+const MAX_IMPORT_SAMPLES: usize = 100;
+
+/// Default lookback for system history query (days)
+const DEFAULT_SYSTEM_HISTORY_DAYS: i64 = 30;
+
+/// Query parameters for system information history
+#[derive(Debug, Deserialize)]
+pub struct SystemHistoryQuery {
+    /// Start time (ISO 8601 format, default: 30 days ago)
+    start: Option<DateTime<Utc>>,
+
+    /// End time (ISO 8601 format, default: now)
+    end: Option<DateTime<Utc>>,
+}
+
 /// GET /api/v1/servers
 ///
 /// List all monitored servers with health status
@@ -163,3 +188,134 @@ pub async fn get_latest_metrics(
         metrics,
     }))
 }
+
+/// POST /api/v1/servers/:id/import
+///
+/// Import metric samples for a monitored server, e.g. to backfill history when
+/// a server is added. Samples are historical by default: they are written
+/// straight to storage, but not evaluated for alerts or streamed to live
+/// clients. Non-historical samples are published like collected metrics.
+///
+/// Only available when an auth token is configured.
+pub async fn import_metrics(
+    State(state): State<ApiState>,
+    Path(server_id): Path<String>,
+    Json(mut request): Json<ImportRequest>,
+) -> ApiResult<Json<ImportResponse>> {
+    let collector = state
+        .collectors
+        .iter()
+        .find(|collector| collector.server_id() == server_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Server '{}' not found", server_id)))?;
+
+    if request.samples.len() > MAX_IMPORT_SAMPLES {
+        return Err(ApiError::InvalidRequest(format!(
+            "Too many samples: {} (max {} per request)",
+            request.samples.len(),
+            MAX_IMPORT_SAMPLES
+        )));
+    }
+
+    if request.historical {
+        let rows = request
+            .samples
+            .into_iter()
+            .map(|sample| {
+                MetricRow::from_server_metrics(
+                    server_id.clone(),
+                    collector.display_name.clone(),
+                    sample.timestamp,
+                    &sample.metrics,
+                )
+            })
+            .collect();
+        let imported = state.storage.insert_batch(rows).await?;
+
+        return Ok(Json(ImportResponse {
+            server_id,
+            imported,
+            historical: true,
+        }));
+    }
+
+    // Publish in collection order so grace periods behave
+    request.samples.sort_by_key(|sample| sample.timestamp);
+
+    let imported = request.samples.len();
+    for sample in request.samples {
+        let event = MetricEvent {
+            server_id: server_id.clone(),
+            metrics: sample.metrics,
+            timestamp: sample.timestamp,
+            display_name: collector.display_name.clone(),
+            collection_ms: None,
+        };
+
+        state
+            .metric_tx
+            .send(event)
+            .map_err(|_| ApiError::Internal("No subscribers for metric events".to_string()))?;
+    }
+
+    Ok(Json(ImportResponse {
+        server_id,
+        imported,
+        historical: false,
+    }))
+}
+
+/// GET /api/v1/servers/:id/system-history
+///
+/// Get the distinct system information snapshots (OS, kernel, hostname) a
+/// server reported within a time range, each with the window it was observed in.
+/// A snapshot that reappears later (e.g. after a rollback) starts a new entry.
+pub async fn get_system_history(
+    State(state): State<ApiState>,
+    Path(server_id): Path<String>,
+    Query(query): Query<SystemHistoryQuery>,
+) -> ApiResult<Json<SystemHistoryResponse>> {
+    let end = query.end.unwrap_or_else(Utc::now);
+    let start = query
+        .start
+        .unwrap_or_else(|| end - Duration::days(DEFAULT_SYSTEM_HISTORY_DAYS));
+
+    let mut snapshots = Vec::new();
+
+    // Scan stored metrics page by page so long windows don't load everything at once
+    let mut pager = RangePager::new(server_id.clone(), start, end, MAX_METRICS_LIMIT);
+    while let Some(range) = pager.next_range() {
+        let rows = pager.advance(state.storage.query_range(range).await?);
+        collect_system_snapshots(&mut snapshots, &rows);
+    }
+
+    let count = snapshots.len();
+
+    Ok(Json(SystemHistoryResponse {
+        server_id,
+        start: start.to_rfc3339(),
+        end: end.to_rfc3339(),
+        count,
+        snapshots,
+    }))
+}
+
+/// Fold metric rows (ordered by timestamp) into runs of identical system information
+fn collect_system_snapshots(snapshots: &mut Vec<SystemSnapshot>, rows: &[MetricRow]) {
+    for row in rows {
+        let system = &row.metadata.system;
+        let timestamp = row.timestamp.to_rfc3339();
+
+        match snapshots.last_mut() {
+            Some(current) if current.system == *system => {
+                current.last_seen = timestamp;
+                current.sample_count += 1;
+            }
+            _ => snapshots.push(SystemSnapshot {
+                system: system.clone(),
+                first_seen: timestamp.clone(),
+                last_seen: timestamp,
+                sample_count: 1,
+            }),
+        }
+    }
+}
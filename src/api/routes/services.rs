@@ -130,28 +130,56 @@ pub async fn get_service_checks(
 /// GET /api/v1/services/:name/uptime
 ///
 /// Get uptime statistics for a service
+///
+/// Compacted runs cover the range up to the compaction watermark (the end of
+/// the latest run), so history is still counted after retention cleanup has
+/// removed the raw checks. Raw checks are only read for the tail after it.
+/// Runs don't keep response times, so the average covers the tail only.
 pub async fn get_uptime(
     State(state): State<ApiState>,
     Path(service_name): Path<String>,
     Query(query): Query<UptimeQuery>,
 ) -> ApiResult<Json<UptimeResponse>> {
+    let now = Utc::now();
     let since = query
         .since
-        .unwrap_or_else(|| Utc::now() - Duration::hours(DEFAULT_LOOKBACK_HOURS));
+        .unwrap_or_else(|| now - Duration::hours(DEFAULT_LOOKBACK_HOURS));
 
-    let uptime_stats = state
+    let runs = state
+        .storage
+        .query_service_check_runs(service_name.clone(), since, now)
+        .await?;
+    let compacted_checks: usize = runs.iter().map(|run| run.count_since(since)).sum();
+    let compacted_successful: usize = runs
+        .iter()
+        .filter(|run| run.status == crate::actors::messages::ServiceStatus::Up)
+        .map(|run| run.count_since(since))
+        .sum();
+
+    let tail_since = runs
+        .last()
+        .map_or(since, |run| since.max(run.end + Duration::milliseconds(1)));
+    let tail = state
         .storage
-        .calculate_uptime(service_name.clone(), since)
+        .calculate_uptime(service_name.clone(), tail_since)
         .await?;
 
+    let total_checks = compacted_checks + tail.total_checks;
+    let successful_checks = compacted_successful + tail.successful_checks;
+    let uptime_percentage = if total_checks > 0 {
+        (successful_checks as f64 / total_checks as f64) * 100.0
+    } else {
+        0.0
+    };
+
     Ok(Json(UptimeResponse {
         service_name,
         since: since.to_rfc3339(),
-        start: uptime_stats.start.to_rfc3339(),
-        end: uptime_stats.end.to_rfc3339(),
-        uptime_percentage: uptime_stats.uptime_percentage,
-        total_checks: uptime_stats.total_checks,
-        successful_checks: uptime_stats.successful_checks,
-        avg_response_time_ms: uptime_stats.avg_response_time_ms,
+        start: since.to_rfc3339(),
+        end: tail.end.to_rfc3339(),
+        uptime_percentage,
+        total_checks,
+        successful_checks,
+        avg_response_time_ms: tail.avg_response_time_ms,
     }))
 }
@@ -8,8 +8,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ServerMetrics,
-    actors::messages::{MetricEvent, ServiceCheckEvent},
+    ServerMetrics, SystemInformation,
+    actors::messages::{AlertChannelHealth, MetricEvent, ServiceCheckEvent},
 };
 
 // ============================================================================
@@ -228,8 +228,12 @@ pub struct ServicesResponse {
 /// Response for GET /api/v1/health
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthResponse {
+    /// "ok", or "degraded" if any alert channel is unhealthy
     pub status: String,
     pub timestamp: String,
+    /// Latest alert channel health check results
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alert_channels: Vec<AlertChannelHealth>,
 }
 
 /// Response for GET /api/v1/servers/:id/metrics
@@ -250,6 +254,73 @@ pub struct LatestMetricsResponse {
     pub metrics: Vec<crate::storage::schema::MetricRow>,
 }
 
+/// A single metric sample in an import request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSample {
+    /// When the metrics were collected
+    pub timestamp: DateTime<Utc>,
+
+    /// The collected metrics
+    pub metrics: ServerMetrics,
+}
+
+/// Request body for POST /api/v1/servers/:id/import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRequest {
+    pub samples: Vec<ImportSample>,
+
+    /// Mark samples as historical backfill so they are stored without
+    /// triggering alerts (default: true)
+    #[serde(default = "default_historical")]
+    pub historical: bool,
+}
+
+fn default_historical() -> bool {
+    true
+}
+
+/// Response for POST /api/v1/servers/:id/import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportResponse {
+    pub server_id: String,
+    pub imported: usize,
+    pub historical: bool,
+}
+
+/// Response for DELETE /api/v1/metrics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteMetricsResponse {
+    pub before: String,
+    pub metrics_deleted: usize,
+    pub service_checks_deleted: usize,
+}
+
+/// A distinct system information snapshot and the window it was observed in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemSnapshot {
+    /// System information reported by the agent
+    pub system: SystemInformation,
+
+    /// First sample with this system information (RFC 3339 timestamp)
+    pub first_seen: String,
+
+    /// Last sample with this system information (RFC 3339 timestamp)
+    pub last_seen: String,
+
+    /// Number of samples in this window
+    pub sample_count: usize,
+}
+
+/// Response for GET /api/v1/servers/:id/system-history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemHistoryResponse {
+    pub server_id: String,
+    pub start: String,
+    pub end: String,
+    pub count: usize,
+    pub snapshots: Vec<SystemSnapshot>,
+}
+
 /// Response for GET /api/v1/services/:name/checks
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceChecksResponse {
@@ -313,6 +384,10 @@ pub enum WsEvent {
         http_status_code: Option<u16>,
         ssl_expiry_days: Option<i64>,
         error_message: Option<String>,
+        #[serde(default)]
+        request_bytes: Option<u64>,
+        #[serde(default)]
+        response_bytes: Option<u64>,
     },
 }
 
@@ -323,6 +398,7 @@ impl From<MetricEvent> for WsEvent {
             metrics,
             timestamp,
             display_name,
+            collection_ms: _,
         } = value;
 
         WsEvent::Metric {
@@ -345,6 +421,8 @@ impl From<ServiceCheckEvent> for WsEvent {
             http_status_code,
             ssl_expiry_days,
             error_message,
+            request_bytes,
+            response_bytes,
         } = value;
 
         WsEvent::ServiceCheck {
@@ -356,6 +434,8 @@ impl From<ServiceCheckEvent> for WsEvent {
             http_status_code,
             ssl_expiry_days,
             error_message,
+            request_bytes,
+            response_bytes,
         }
     }
 }
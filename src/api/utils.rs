@@ -59,6 +59,7 @@ pub const DOWN_THRESHOLD_SECS: i64 = 120; // 2 minutes
 // ============================================================================
 
 use chrono::{DateTime, Utc};
+use std::net::{IpAddr, SocketAddr};
 use std::time::Duration;
 
 /// Determine if metrics are stale based on timestamp
@@ -105,8 +106,13 @@ pub fn handle_http_error(error: &reqwest::Error) -> String {
 }
 
 /// Build a complete URL for monitoring endpoints
+///
+/// IPv6 literals are bracketed (`http://[::1]:3000/metrics`).
 pub fn build_monitoring_url(ip: &str, port: u16, endpoint: &str) -> String {
-    format!("http://{}:{}{}", ip, port, endpoint)
+    match ip.parse::<IpAddr>() {
+        Ok(addr) => format!("http://{}{}", SocketAddr::new(addr, port), endpoint),
+        Err(_) => format!("http://{}:{}{}", ip, port, endpoint),
+    }
 }
 
 /// Determine if polling is stale based on timestamp
@@ -1,11 +1,12 @@
 use axum::routing::trace;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use guardia::{
     actors::{
         alert::AlertHandle, collector::CollectorHandle, service_monitor::ServiceHandle,
         storage::StorageHandle,
     },
     config::{ResolvedConfig, StorageConfig, read_config_file},
+    monitors::replay::ReplayLimit,
 };
 use tokio::sync::broadcast;
 use tracing::{error, info, level_filters::LevelFilter, trace, warn};
@@ -19,6 +20,34 @@ struct Args {
     /// Config file
     #[arg(short)]
     file: String,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum Command {
+    /// Replay stored history against proposed alert limits (dry run, nothing is sent)
+    Tune(TuneArgs),
+}
+
+#[derive(Debug, Clone, clap::Args)]
+struct TuneArgs {
+    /// Server to replay (server id "ip:port", IP or display name)
+    #[arg(long)]
+    server: String,
+
+    /// Proposed limit, e.g. cpu=80 or temp=75 (repeatable)
+    #[arg(long = "limit", required = true)]
+    limits: Vec<ReplayLimit>,
+
+    /// Consecutive samples at or above the limit before alerting
+    #[arg(long, default_value_t = 0)]
+    grace: usize,
+
+    /// Start of the replay window: RFC 3339 timestamp or relative (30m, 24h, 7d)
+    #[arg(long, default_value = "24h")]
+    since: String,
 }
 
 fn init() {
@@ -62,6 +91,10 @@ async fn main() -> anyhow::Result<()> {
         resolved_config.services.len()
     );
 
+    if let Some(Command::Tune(tune_args)) = args.command {
+        return run_tune(&resolved_config, tune_args).await;
+    }
+
     // Run the actor-based monitoring system
     run_monitoring(resolved_config).await?;
 
@@ -96,7 +129,7 @@ async fn run_monitoring(resolved_config: ResolvedConfig) -> anyhow::Result<()> {
 
     // Initialize storage backend based on config
     #[cfg(feature = "storage-sqlite")]
-    let (backend, retention_days, cleanup_interval_hours) =
+    let (backend, retention_days, cleanup_interval_hours, compaction_interval_hours) =
         initialize_storage_backend(&resolved_config.storage).await;
 
     // Spawn storage actor with optional persistent backend
@@ -107,6 +140,7 @@ async fn run_monitoring(resolved_config: ResolvedConfig) -> anyhow::Result<()> {
         backend,
         retention_days,
         cleanup_interval_hours,
+        compaction_interval_hours,
     );
 
     #[cfg(not(feature = "storage-sqlite"))]
@@ -115,21 +149,28 @@ async fn run_monitoring(resolved_config: ResolvedConfig) -> anyhow::Result<()> {
     info!("storage actor started");
 
     // Spawn alert actor with all server and service configs
-    let alert_handle = AlertHandle::spawn(
+    let alert_handle = AlertHandle::spawn_with_health_check(
         servers.clone(),
         services.clone(),
         metric_tx.subscribe(),
         service_tx.subscribe(),
+        &resolved_config.alert_health,
     );
     info!("alert actor started");
 
+    // Compare the first polls against the inventory stored before a restart
+    #[cfg(feature = "storage-sqlite")]
+    alert_handle
+        .seed_inventory_from_storage(&storage_handle, &servers)
+        .await;
+
     // Spawn collector actor for each server
     let mut collector_handles = Vec::new();
     for server_config in servers {
         let display_name = server_config
             .display
             .clone()
-            .unwrap_or_else(|| format!("{}:{}", server_config.ip, server_config.port));
+            .unwrap_or_else(|| server_config.server_id());
 
         let handle =
             CollectorHandle::spawn(server_config.clone(), metric_tx.clone(), polling_tx.clone());
@@ -234,17 +275,91 @@ async fn run_monitoring(resolved_config: ResolvedConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Replay stored history through a dry-run alert evaluation and print would-fire alerts
+#[cfg(feature = "storage-sqlite")]
+async fn run_tune(resolved_config: &ResolvedConfig, args: TuneArgs) -> anyhow::Result<()> {
+    use chrono::Utc;
+    use guardia::monitors::replay::{parse_since, replay_history};
+
+    let server = resolved_config
+        .servers
+        .iter()
+        .find(|server| {
+            args.server == server.server_id()
+                || args.server == server.ip.to_string()
+                || server.display.as_deref() == Some(args.server.as_str())
+        })
+        .ok_or_else(|| anyhow::anyhow!("server '{}' not found in configuration", args.server))?;
+    let server_id = server.server_id();
+
+    let Some(StorageConfig::Sqlite { path, .. }) = &resolved_config.storage else {
+        anyhow::bail!("tune requires a sqlite storage backend with recorded history");
+    };
+    let backend = SqliteBackend::new(path).await?;
+
+    let end = Utc::now();
+    let start = parse_since(&args.since, end).map_err(|e| anyhow::anyhow!(e))?;
+
+    let report = replay_history(&backend, &server_id, start, end, &args.limits, args.grace).await?;
+
+    println!(
+        "replayed {} samples for {} from {} to {} (grace {}, dry run)",
+        report.samples,
+        server.display.as_deref().unwrap_or(&server_id),
+        report.start.to_rfc3339(),
+        report.end.to_rfc3339(),
+        args.grace
+    );
+
+    for result in &report.results {
+        println!();
+        println!(
+            "{}: {} alert(s) would have fired",
+            result.limit,
+            result.alerts.len()
+        );
+        for alert in &result.alerts {
+            match alert.recovered_at {
+                Some(recovered_at) => println!(
+                    "  {}  {:.1}  (recovered {})",
+                    alert.fired_at.to_rfc3339(),
+                    alert.value,
+                    recovered_at.to_rfc3339()
+                ),
+                None => println!(
+                    "  {}  {:.1}  (not recovered)",
+                    alert.fired_at.to_rfc3339(),
+                    alert.value
+                ),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "storage-sqlite"))]
+async fn run_tune(_resolved_config: &ResolvedConfig, _args: TuneArgs) -> anyhow::Result<()> {
+    anyhow::bail!("tune requires the storage-sqlite feature")
+}
+
 /// Initialize storage backend based on configuration
-/// Returns (backend, retention_days, cleanup_interval_hours)
+/// Returns (backend, retention_days, cleanup_interval_hours, compaction_interval_hours)
 #[cfg(feature = "storage-sqlite")]
 async fn initialize_storage_backend(
     storage_config: &Option<StorageConfig>,
-) -> (Option<Box<dyn StorageBackend>>, Option<u32>, Option<u32>) {
+) -> (
+    Option<Box<dyn StorageBackend>>,
+    Option<u32>,
+    Option<u32>,
+    Option<u32>,
+) {
     match storage_config {
         Some(StorageConfig::Sqlite {
             path,
             retention_days,
             cleanup_interval_hours,
+            compaction_interval_hours,
         }) => {
             info!(
                 "initializing SQLite backend at: {:?} (retention: {} days, cleanup: every {} hours)",
@@ -257,18 +372,19 @@ async fn initialize_storage_backend(
                         Some(Box::new(backend) as Box<dyn StorageBackend>),
                         Some(*retention_days),
                         Some(*cleanup_interval_hours),
+                        *compaction_interval_hours,
                     )
                 }
                 Err(e) => {
                     error!("failed to initialize SQLite backend: {}", e);
                     warn!("falling back to in-memory storage");
-                    (None, None, None)
+                    (None, None, None, None)
                 }
             }
         }
         Some(StorageConfig::None) | None => {
             info!("using in-memory storage (no persistence)");
-            (None, None, None)
+            (None, None, None, None)
         }
     }
 }
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
-use std::net::IpAddr;
-use std::path::PathBuf;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use cron::Schedule;
 
 use tracing::trace;
 
@@ -25,6 +28,11 @@ pub enum StorageConfig {
         /// Cleanup interval in hours (how often to run retention cleanup)
         #[serde(default = "default_cleanup_interval_hours")]
         cleanup_interval_hours: u32,
+
+        /// Compaction interval in hours (how often to collapse service check
+        /// runs; compaction is disabled if not set)
+        #[serde(default)]
+        compaction_interval_hours: Option<u32>,
     },
     // Future: PostgreSQL, Parquet, etc.
 }
@@ -37,6 +45,7 @@ impl StorageConfig {
             StorageConfig::Sqlite {
                 retention_days,
                 cleanup_interval_hours,
+                compaction_interval_hours,
                 ..
             } => {
                 // Validate retention_days: 1 day to 10 years
@@ -55,6 +64,11 @@ impl StorageConfig {
                     return Err("cleanup_interval_hours cannot exceed 720 (30 days)".to_string());
                 }
 
+                // Validate compaction_interval_hours: 1 hour to 30 days
+                if compaction_interval_hours.is_some_and(|hours| !(1..=720).contains(&hours)) {
+                    return Err("compaction_interval_hours must be between 1 and 720".to_string());
+                }
+
                 // Warn if cleanup interval is longer than retention period
                 let retention_hours = *retention_days as u64 * 24;
                 if (*cleanup_interval_hours as u64) > retention_hours {
@@ -78,10 +92,49 @@ impl Default for StorageConfig {
             path: default_sqlite_path(),
             retention_days: default_retention_days(),
             cleanup_interval_hours: default_cleanup_interval_hours(),
+            compaction_interval_hours: None,
         }
     }
 }
 
+/// Alert channel health check configuration
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AlertHealthConfig {
+    /// Whether alert channels are validated at startup and periodically
+    #[serde(default = "default_alert_health_enabled")]
+    pub enabled: bool,
+
+    /// Interval between checks in seconds
+    #[serde(default = "default_alert_health_interval")]
+    pub interval: u64,
+
+    /// Request timeout per channel in seconds
+    #[serde(default = "default_alert_health_timeout")]
+    pub timeout: u64,
+}
+
+impl Default for AlertHealthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_alert_health_enabled(),
+            interval: default_alert_health_interval(),
+            timeout: default_alert_health_timeout(),
+        }
+    }
+}
+
+fn default_alert_health_enabled() -> bool {
+    true
+}
+
+fn default_alert_health_interval() -> u64 {
+    3600 // Re-check every hour
+}
+
+fn default_alert_health_timeout() -> u64 {
+    10
+}
+
 fn default_sqlite_path() -> PathBuf {
     PathBuf::from("./metrics.db")
 }
@@ -110,6 +163,9 @@ pub struct Config {
     /// Service monitoring configuration (HTTP/HTTPS endpoints)
     pub services: Option<Vec<ServiceConfig>>,
 
+    /// Alert channel health checks (optional - enabled with defaults if omitted)
+    pub alert_health: Option<AlertHealthConfig>,
+
     /// API server configuration (optional - API disabled if not specified)
     #[cfg(feature = "api")]
     pub api: Option<ApiConfig>,
@@ -201,6 +257,8 @@ pub struct ServerConfig {
 pub struct Limits {
     pub temperature: Option<Limit>,
     pub usage: Option<Limit>,
+    pub latency: Option<LatencyLimit>,
+    pub inventory: Option<InventoryLimit>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -208,6 +266,25 @@ pub struct Limits {
 pub enum Alert {
     Discord(Discord),
     Webhook(Webhook),
+    File(FileSink),
+}
+
+impl Alert {
+    /// Where the alert channel delivers to, identifying it across limits and services
+    pub fn target(&self) -> AlertTarget<'_> {
+        match self {
+            Alert::Discord(discord) => AlertTarget::Url(&discord.url),
+            Alert::Webhook(webhook) => AlertTarget::Url(&webhook.url),
+            Alert::File(sink) => AlertTarget::Path(&sink.path),
+        }
+    }
+}
+
+/// Delivery target of an alert channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertTarget<'a> {
+    Url(&'a str),
+    Path(&'a Path),
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -215,6 +292,39 @@ pub struct Webhook {
     pub url: String,
 }
 
+/// Local file that alert records are appended to (for air-gapped hosts)
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FileSink {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub format: FileFormat,
+    /// Rotate the file once it would grow beyond this many bytes
+    #[serde(default = "default_file_sink_max_bytes")]
+    pub max_bytes: u64,
+    /// Number of rotated files to keep (`<path>.1` is the most recent)
+    #[serde(default = "default_file_sink_max_files")]
+    pub max_files: usize,
+}
+
+/// Record format of a file alert sink
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileFormat {
+    /// One JSON object per line
+    #[default]
+    Json,
+    /// One human-readable line per alert
+    Text,
+}
+
+fn default_file_sink_max_bytes() -> u64 {
+    10 * 1024 * 1024 // 10 MiB
+}
+
+fn default_file_sink_max_files() -> usize {
+    5
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct Discord {
     pub url: String,
@@ -229,6 +339,35 @@ pub struct Limit {
     pub alert: Option<String>,
 }
 
+/// Limit on how long polling the agent's /metrics endpoint takes
+///
+/// Growing poll latency hints at agent or network degradation before polls
+/// fail outright. Either condition (or both) can be configured.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LatencyLimit {
+    /// Alert when a poll takes at least this many milliseconds
+    pub limit: Option<u64>,
+    /// Alert when poll latency grew over this many consecutive polls
+    pub trend: Option<usize>,
+    pub grace: Option<usize>,
+    /// Alert name reference (looks up in Config.alerts registry)
+    pub alert: Option<String>,
+}
+
+/// Alert when a server's CPU inventory (core count or architecture) changes
+///
+/// An unexpected change hints at a resized VM or a collector pointed at the
+/// wrong host. Planned changes can be allowlisted.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct InventoryLimit {
+    /// Core counts a server may change to without alerting
+    pub allowed_cpus: Option<Vec<usize>>,
+    /// Architectures a server may change to without alerting
+    pub allowed_arch: Option<Vec<String>>,
+    /// Alert name reference (looks up in Config.alerts registry)
+    pub alert: Option<String>,
+}
+
 /// HTTP method for service checks
 #[derive(Debug, Default, Clone, serde::Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -239,6 +378,21 @@ pub enum HttpMethod {
     Head,
 }
 
+/// Protocol used to check a service
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckType {
+    /// HTTP/HTTPS request, validated by status code and body
+    #[default]
+    Http,
+    /// gRPC Health Checking protocol (`grpc.health.v1.Health/Check`)
+    ///
+    /// The URL path selects the checked service, e.g.
+    /// `http://10.0.0.5:50051/orders.v1.Orders`. Without a path the overall
+    /// server health is checked. Requires the `grpc` feature.
+    Grpc,
+}
+
 /// Service monitoring configuration (HTTP/HTTPS endpoints)
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct ServiceConfig {
@@ -252,10 +406,18 @@ pub struct ServiceConfig {
     #[serde(default = "default_service_interval")]
     pub interval: usize,
 
+    /// Cron expression (UTC, with seconds, e.g. "0 0 9 * * Mon-Fri")
+    /// When set, checks run at the scheduled times instead of every `interval` seconds
+    pub cron: Option<String>,
+
     /// Request timeout in seconds
     #[serde(default = "default_service_timeout")]
     pub timeout: usize,
 
+    /// Check protocol (defaults to HTTP)
+    #[serde(default)]
+    pub check: CheckType,
+
     /// HTTP method to use
     #[serde(default)]
     pub method: HttpMethod,
@@ -267,6 +429,10 @@ pub struct ServiceConfig {
     /// Optional regex pattern to match in response body
     pub body_pattern: Option<String>,
 
+    /// Maximum expected response body size in bytes
+    /// Larger responses mark the service as degraded (e.g. error pages, data leaks)
+    pub max_body_bytes: Option<u64>,
+
     /// Consecutive failures before alerting
     pub grace: Option<usize>,
 
@@ -295,6 +461,7 @@ pub struct ResolvedConfig {
     pub servers: Vec<ResolvedServerConfig>,
     pub services: Vec<ResolvedServiceConfig>,
     pub storage: Option<StorageConfig>,
+    pub alert_health: AlertHealthConfig,
     #[cfg(feature = "api")]
     pub api: Option<ApiConfig>,
 }
@@ -310,11 +477,29 @@ pub struct ResolvedServerConfig {
     pub limits: Option<ResolvedLimits>,
 }
 
+impl ResolvedServerConfig {
+    /// Key identifying this server in events, storage and the API (`ip:port`)
+    ///
+    /// IPv6 addresses are not bracketed here so existing keys stay stable.
+    pub fn server_id(&self) -> String {
+        format!("{}:{}", self.ip, self.port)
+    }
+
+    /// URL of an agent endpoint, e.g. `agent_url("/metrics")`
+    ///
+    /// IPv6 literals are bracketed (`http://[::1]:3000/metrics`).
+    pub fn agent_url(&self, endpoint: &str) -> String {
+        format!("http://{}{}", SocketAddr::new(self.ip, self.port), endpoint)
+    }
+}
+
 /// Resolved limits configuration
 #[derive(Debug, Clone)]
 pub struct ResolvedLimits {
     pub temperature: Option<ResolvedLimit>,
     pub usage: Option<ResolvedLimit>,
+    pub latency: Option<ResolvedLatencyLimit>,
+    pub inventory: Option<ResolvedInventoryLimit>,
 }
 
 /// Resolved limit with actual Alert object
@@ -325,16 +510,36 @@ pub struct ResolvedLimit {
     pub alert: Option<Alert>,
 }
 
+/// Resolved poll latency limit with actual Alert object
+#[derive(Debug, Clone)]
+pub struct ResolvedLatencyLimit {
+    pub limit: Option<u64>,
+    pub trend: Option<usize>,
+    pub grace: Option<usize>,
+    pub alert: Option<Alert>,
+}
+
+/// Resolved CPU inventory limit with actual Alert object
+#[derive(Debug, Clone)]
+pub struct ResolvedInventoryLimit {
+    pub allowed_cpus: Vec<usize>,
+    pub allowed_arch: Vec<String>,
+    pub alert: Option<Alert>,
+}
+
 /// Resolved service configuration with actual Alert object
 #[derive(Debug, Clone)]
 pub struct ResolvedServiceConfig {
     pub name: String,
     pub url: String,
     pub interval: usize,
+    pub cron: Option<Schedule>,
     pub timeout: usize,
+    pub check: CheckType,
     pub method: HttpMethod,
     pub expected_status: Option<Vec<u16>>,
     pub body_pattern: Option<String>,
+    pub max_body_bytes: Option<u64>,
     pub grace: Option<usize>,
     pub alert: Option<Alert>,
 }
@@ -365,6 +570,59 @@ impl Config {
             }
         };
 
+        // Helper to merge a server's latency limit with the default one
+        let resolve_latency = |server: Option<LatencyLimit>,
+                               default: Option<LatencyLimit>|
+         -> anyhow::Result<Option<ResolvedLatencyLimit>> {
+            let merged = match (server, default) {
+                (Some(server), Some(default)) => Some(LatencyLimit {
+                    limit: server.limit.or(default.limit),
+                    trend: server.trend.or(default.trend),
+                    grace: server.grace.or(default.grace),
+                    alert: server.alert.or(default.alert),
+                }),
+                (server, default) => server.or(default),
+            };
+
+            merged
+                .map(|latency| {
+                    if latency.trend == Some(0) {
+                        anyhow::bail!("Latency trend must span at least 1 poll");
+                    }
+                    Ok(ResolvedLatencyLimit {
+                        limit: latency.limit,
+                        trend: latency.trend,
+                        grace: latency.grace,
+                        alert: resolve_alert(&latency.alert)?,
+                    })
+                })
+                .transpose()
+        };
+
+        // Helper to merge a server's inventory limit with the default one
+        let resolve_inventory = |server: Option<InventoryLimit>,
+                                 default: Option<InventoryLimit>|
+         -> anyhow::Result<Option<ResolvedInventoryLimit>> {
+            let merged = match (server, default) {
+                (Some(server), Some(default)) => Some(InventoryLimit {
+                    allowed_cpus: server.allowed_cpus.or(default.allowed_cpus),
+                    allowed_arch: server.allowed_arch.or(default.allowed_arch),
+                    alert: server.alert.or(default.alert),
+                }),
+                (server, default) => server.or(default),
+            };
+
+            merged
+                .map(|inventory| {
+                    Ok(ResolvedInventoryLimit {
+                        allowed_cpus: inventory.allowed_cpus.unwrap_or_default(),
+                        allowed_arch: inventory.allowed_arch.unwrap_or_default(),
+                        alert: resolve_alert(&inventory.alert)?,
+                    })
+                })
+                .transpose()
+        };
+
         // Get default configurations
         let default_server = self.defaults.as_ref().and_then(|d| d.server.as_ref());
         let default_service = self.defaults.as_ref().and_then(|d| d.service.as_ref());
@@ -423,6 +681,14 @@ impl Config {
                                 }),
                                 (None, None) => None,
                             },
+                            latency: resolve_latency(
+                                server_limits.latency,
+                                default_limits.latency,
+                            )?,
+                            inventory: resolve_inventory(
+                                server_limits.inventory,
+                                default_limits.inventory,
+                            )?,
                         })
                     }
                     (Some(server_limits), None) => {
@@ -448,6 +714,8 @@ impl Config {
                                     })
                                 })
                                 .transpose()?,
+                            latency: resolve_latency(server_limits.latency, None)?,
+                            inventory: resolve_inventory(server_limits.inventory, None)?,
                         })
                     }
                     (None, Some(default_limits)) => {
@@ -473,6 +741,8 @@ impl Config {
                                     })
                                 })
                                 .transpose()?,
+                            latency: resolve_latency(None, default_limits.latency)?,
+                            inventory: resolve_inventory(None, default_limits.inventory)?,
                         })
                     }
                     (None, None) => None,
@@ -504,14 +774,51 @@ impl Config {
                         .or_else(|| default_service.and_then(|d| d.alert.clone())),
                 )?;
 
+                let cron = service
+                    .cron
+                    .as_deref()
+                    .map(|expression| {
+                        Schedule::from_str(expression).map_err(|e| {
+                            anyhow::anyhow!(
+                                "Invalid cron expression '{}' for service '{}': {}",
+                                expression,
+                                service.name,
+                                e
+                            )
+                        })
+                    })
+                    .transpose()?;
+
+                // A schedule that never fires again would silently stop checking the service
+                if let (Some(schedule), Some(expression)) = (&cron, &service.cron)
+                    && schedule.upcoming(chrono::Utc).next().is_none()
+                {
+                    anyhow::bail!(
+                        "Cron expression '{}' for service '{}' has no upcoming fire times",
+                        expression,
+                        service.name
+                    );
+                }
+
+                #[cfg(not(feature = "grpc"))]
+                if service.check == CheckType::Grpc {
+                    anyhow::bail!(
+                        "Service '{}' uses a gRPC check, which requires the grpc feature",
+                        service.name
+                    );
+                }
+
                 Ok(ResolvedServiceConfig {
                     name: service.name,
                     url: service.url,
                     interval: service.interval,
+                    cron,
                     timeout: service.timeout,
+                    check: service.check,
                     method: service.method,
                     expected_status: service.expected_status,
                     body_pattern: service.body_pattern,
+                    max_body_bytes: service.max_body_bytes,
                     grace: service
                         .grace
                         .or_else(|| default_service.and_then(|d| d.grace)),
@@ -524,6 +831,7 @@ impl Config {
             servers,
             services,
             storage: self.storage,
+            alert_health: self.alert_health.unwrap_or_default(),
             #[cfg(feature = "api")]
             api: self.api,
         })
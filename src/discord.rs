@@ -91,7 +91,7 @@ impl DiscordManager {
         self.server_config
             .display
             .clone()
-            .unwrap_or_else(|| format!("{}:{}", self.server_config.ip, self.server_config.port))
+            .unwrap_or_else(|| self.server_config.server_id())
     }
 
     pub fn build_temperature_embed(
@@ -206,6 +206,72 @@ impl DiscordManager {
         }
     }
 
+    /// Build Discord embed for agent poll latency alert
+    pub fn build_latency_embed(
+        &self,
+        evaluation: ResourceEvaluation,
+        collection_ms: u64,
+        reason: &str,
+    ) -> Embed {
+        let server = self.server_display();
+
+        let (title, description, color) = match evaluation {
+            ResourceEvaluation::StartsToExceed => (
+                "🐢 Poll Latency Alert",
+                format!("Polling server **{}** is getting slow ({})", server, reason),
+                15105570, // Orange
+            ),
+            ResourceEvaluation::BackToOk => (
+                "✅ Poll Latency Recovered",
+                format!("Polling server **{}** is back to normal", server),
+                3066993, // Green
+            ),
+            _ => (
+                "⏱️ Poll Latency Update",
+                format!("Poll latency update for server **{}**", server),
+                5793266, // Light blue
+            ),
+        };
+
+        Embed {
+            title: Some(title.to_string()),
+            description: Some(description),
+            color: Some(color),
+            fields: vec![EmbedField {
+                name: "⏱️ Poll Duration".to_string(),
+                value: format!("{}ms", collection_ms),
+                inline: true,
+            }],
+            footer: Some(EmbedFooter {
+                text: format!("Server: {} | {}", server, self.server_config.ip),
+            }),
+            timestamp: Some(Utc::now().to_rfc3339()),
+        }
+    }
+
+    /// Build Discord embed for CPU inventory change alert
+    pub fn build_inventory_embed(&self, changes: &[String]) -> Embed {
+        let server = self.server_display();
+
+        Embed {
+            title: Some("🧩 CPU Inventory Changed".to_string()),
+            description: Some(format!(
+                "Server **{}** reports different CPU hardware than before",
+                server
+            )),
+            color: Some(15105570), // Orange
+            fields: vec![EmbedField {
+                name: "🔧 Changes".to_string(),
+                value: changes.join("\n"),
+                inline: false,
+            }],
+            footer: Some(EmbedFooter {
+                text: format!("Server: {} | {}", server, self.server_config.ip),
+            }),
+            timestamp: Some(Utc::now().to_rfc3339()),
+        }
+    }
+
     /// Build Discord embed for service health alert (Phase 3)
     pub fn build_service_embed(
         &self,
@@ -0,0 +1,171 @@
+//! File alert sink
+//!
+//! Appends alert records to a local file, so hosts without access to Discord
+//! or webhook endpoints can still alert through local log tooling (e.g. a log
+//! shipper or `tail -F`). Files are rotated by size: `<path>` is renamed to
+//! `<path>.1`, `<path>.1` to `<path>.2` and so on, up to `max_files`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+
+use serde_json::Value;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{error, info, instrument};
+
+use crate::config::{FileFormat, FileSink};
+
+/// Per-path locks serializing appends and rotations across all alert
+/// managers sharing a file, without blocking writes to other files
+static WRITE_LOCKS: LazyLock<std::sync::Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> =
+    LazyLock::new(Default::default);
+
+fn write_lock(path: &Path) -> Arc<Mutex<()>> {
+    let mut locks = WRITE_LOCKS.lock().unwrap_or_else(|e| e.into_inner());
+    locks.entry(path.to_path_buf()).or_default().clone()
+}
+
+/// Append an alert record to the sink, rotating the file first if needed
+///
+/// The record must be a JSON object with at least `timestamp`, `kind` and
+/// `message` fields. Errors are logged, not returned, like other alert channels.
+#[instrument(skip(record), fields(path = %sink.path.display()))]
+pub async fn send_record(sink: &FileSink, record: &Value) {
+    let line = format_record(sink.format, record);
+
+    let lock = write_lock(&sink.path);
+    let _guard = lock.lock().await;
+
+    if let Err(e) = rotate_if_needed(sink, line.len() as u64).await {
+        error!("Failed to rotate alert file: {}", e);
+    }
+
+    match append_line(&sink.path, &line).await {
+        Ok(()) => info!("Successfully wrote alert to file"),
+        Err(e) => error!("Failed to write alert to file: {}", e),
+    }
+}
+
+/// Render a record as a single line (including the trailing newline)
+fn format_record(format: FileFormat, record: &Value) -> String {
+    match format {
+        FileFormat::Json => format!("{}\n", record),
+        FileFormat::Text => {
+            let field = |name: &str| record.get(name).and_then(Value::as_str).unwrap_or("-");
+            format!(
+                "{} [{}] {}\n",
+                field("timestamp"),
+                field("kind"),
+                field("message").replace('\n', " ")
+            )
+        }
+    }
+}
+
+async fn append_line(path: &Path, line: &str) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(line.as_bytes()).await?;
+    file.flush().await
+}
+
+/// Rotate the file if appending `incoming` bytes would exceed `max_bytes`
+///
+/// A file that is still empty is never rotated, so a single oversized record
+/// is written rather than dropped.
+async fn rotate_if_needed(sink: &FileSink, incoming: u64) -> std::io::Result<()> {
+    let size = match fs::metadata(&sink.path).await {
+        Ok(metadata) => metadata.len(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    if size == 0 || size + incoming <= sink.max_bytes {
+        return Ok(());
+    }
+
+    if sink.max_files == 0 {
+        return fs::remove_file(&sink.path).await;
+    }
+
+    // Shift older files up, dropping the oldest
+    for index in (1..sink.max_files).rev() {
+        let from = rotated_path(&sink.path, index);
+        if fs::try_exists(&from).await? {
+            fs::rename(&from, rotated_path(&sink.path, index + 1)).await?;
+        }
+    }
+
+    fs::rename(&sink.path, rotated_path(&sink.path, 1)).await
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{index}"));
+    PathBuf::from(rotated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sink(path: PathBuf, format: FileFormat, max_bytes: u64) -> FileSink {
+        FileSink {
+            path,
+            format,
+            max_bytes,
+            max_files: 2,
+        }
+    }
+
+    fn record(message: &str) -> Value {
+        json!({
+            "timestamp": "2025-01-04T12:00:00+00:00",
+            "kind": "temperature",
+            "server": "web-01",
+            "message": message,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_text_format() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("alerts.log");
+        let sink = sink(path.clone(), FileFormat::Text, 1024);
+
+        send_record(&sink, &record("line one\nline two")).await;
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "2025-01-04T12:00:00+00:00 [temperature] line one line two\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_max_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("alerts.jsonl");
+        // Every record exceeds half the limit, so each append rotates
+        let sink = sink(path.clone(), FileFormat::Json, 150);
+
+        for i in 0..4 {
+            send_record(&sink, &record(&format!("alert {i}"))).await;
+        }
+
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert!(read(path.clone()).contains("alert 3"));
+        assert!(read(rotated_path(&path, 1)).contains("alert 2"));
+        assert!(read(rotated_path(&path, 2)).contains("alert 1"));
+        assert!(!rotated_path(&path, 3).exists());
+
+        // Each file holds exactly one record
+        assert_eq!(read(path).lines().count(), 1);
+    }
+}
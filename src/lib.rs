@@ -4,6 +4,7 @@ pub mod alerts;
 pub mod api;
 pub mod config;
 pub mod discord;
+pub mod file_sink;
 pub mod monitors;
 pub mod storage;
 pub mod util;
@@ -20,7 +21,7 @@ pub struct ServerMetrics {
     pub components: ComponentOverview,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct SystemInformation {
     pub name: Option<String>,
     pub kernel_version: Option<String>,
@@ -1,3 +1,4 @@
+pub mod replay;
 pub mod resources;
 // Legacy code - replaced by actor-based architecture
 // pub mod server;
@@ -0,0 +1,344 @@
+//! Dry-run alert replay over stored metric history
+//!
+//! Feeds historical metrics through the same grace period state machine the
+//! AlertActor uses ([`ResourceEvaluation`]) with proposed thresholds, without
+//! sending any notifications. Used by `guardia-hub tune` to try out limits
+//! against real data before putting them into the config.
+//!
+//! ## Example
+//!
+//! ```text
+//! guardia-hub -f config.json tune --server 192.168.1.100:3000 --limit cpu=80 --grace 3 --since 7d
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::monitors::resources::ResourceEvaluation;
+use crate::storage::{StorageBackend, StorageResult, backend::RangePager, schema::MetricRow};
+
+/// Resource that can be replayed against a limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayResource {
+    /// Average CPU usage (%)
+    Cpu,
+    /// Average component temperature (°C)
+    Temperature,
+}
+
+impl ReplayResource {
+    /// Extract the evaluated value from a stored metric row
+    fn value(&self, row: &MetricRow) -> Option<f32> {
+        match self {
+            ReplayResource::Cpu => Some(row.metadata.cpus.average_usage),
+            ReplayResource::Temperature => row.metadata.components.average_temperature,
+        }
+    }
+
+    fn unit(&self) -> &'static str {
+        match self {
+            ReplayResource::Cpu => "%",
+            ReplayResource::Temperature => "°C",
+        }
+    }
+}
+
+impl fmt::Display for ReplayResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayResource::Cpu => f.write_str("cpu"),
+            ReplayResource::Temperature => f.write_str("temp"),
+        }
+    }
+}
+
+/// Proposed limit for a resource, parsed from `cpu=80` or `temp=75`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayLimit {
+    pub resource: ReplayResource,
+    pub limit: f32,
+}
+
+impl FromStr for ReplayLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (resource, limit) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected <resource>=<limit>, got '{s}'"))?;
+
+        let resource = match resource.trim() {
+            "cpu" | "usage" => ReplayResource::Cpu,
+            "temp" | "temperature" => ReplayResource::Temperature,
+            other => return Err(format!("unknown resource '{other}' (use cpu or temp)")),
+        };
+
+        let limit = limit
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid limit '{limit}'"))?;
+
+        Ok(Self { resource, limit })
+    }
+}
+
+impl fmt::Display for ReplayLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} >= {}{}",
+            self.resource,
+            self.limit,
+            self.resource.unit()
+        )
+    }
+}
+
+/// An alert that would have fired during replay
+#[derive(Debug, Clone, PartialEq)]
+pub struct WouldFire {
+    /// When the alert would have been sent
+    pub fired_at: DateTime<Utc>,
+
+    /// Value that triggered the alert
+    pub value: f32,
+
+    /// When the recovery alert would have been sent (None if still exceeding)
+    pub recovered_at: Option<DateTime<Utc>>,
+}
+
+/// Replay results for a single limit
+#[derive(Debug, Clone)]
+pub struct LimitReplay {
+    pub limit: ReplayLimit,
+    pub alerts: Vec<WouldFire>,
+}
+
+/// Result of replaying a server's history against proposed limits
+#[derive(Debug, Clone)]
+pub struct ReplayReport {
+    pub server_id: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+
+    /// Number of stored samples replayed
+    pub samples: usize,
+
+    pub results: Vec<LimitReplay>,
+}
+
+/// Number of rows loaded per query while replaying history
+const REPLAY_PAGE_SIZE: usize = 10_000;
+
+/// Alert state machine for a single limit, fed one row at a time
+struct AlertReplay {
+    limit: ReplayLimit,
+    grace: usize,
+    grace_counter: usize,
+    alerts: Vec<WouldFire>,
+}
+
+impl AlertReplay {
+    fn new(limit: ReplayLimit, grace: usize) -> Self {
+        Self {
+            limit,
+            grace,
+            grace_counter: 0,
+            alerts: Vec::new(),
+        }
+    }
+
+    fn feed(&mut self, row: &MetricRow) {
+        let Some(value) = self.limit.resource.value(row) else {
+            return;
+        };
+
+        match ResourceEvaluation::evaluate(value, self.limit.limit, self.grace, self.grace_counter)
+        {
+            ResourceEvaluation::Ok => self.grace_counter = 0,
+            ResourceEvaluation::Exceeding => self.grace_counter += 1,
+            ResourceEvaluation::StartsToExceed => {
+                self.grace_counter += 1;
+                self.alerts.push(WouldFire {
+                    fired_at: row.timestamp,
+                    value,
+                    recovered_at: None,
+                });
+            }
+            ResourceEvaluation::BackToOk => {
+                self.grace_counter = 0;
+                if let Some(alert) = self.alerts.last_mut() {
+                    alert.recovered_at = Some(row.timestamp);
+                }
+            }
+        }
+    }
+}
+
+/// Replay metric rows (ordered by timestamp) through the alert state machine
+///
+/// Mirrors the AlertActor: an alert fires once the limit has been reached for
+/// `grace + 1` consecutive samples, and a recovery is recorded when the value
+/// drops back below the limit after an alert. Samples without a value
+/// (e.g. no temperature sensors) are skipped.
+pub fn replay_alerts(rows: &[MetricRow], limit: &ReplayLimit, grace: usize) -> Vec<WouldFire> {
+    let mut replay = AlertReplay::new(*limit, grace);
+    rows.iter().for_each(|row| replay.feed(row));
+    replay.alerts
+}
+
+/// Load a server's stored history and replay it against each proposed limit
+///
+/// History is loaded in pages of [`REPLAY_PAGE_SIZE`] rows so long ranges
+/// don't have to fit into memory at once.
+pub async fn replay_history(
+    backend: &dyn StorageBackend,
+    server_id: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    limits: &[ReplayLimit],
+    grace: usize,
+) -> StorageResult<ReplayReport> {
+    let mut replays: Vec<_> = limits
+        .iter()
+        .map(|limit| AlertReplay::new(*limit, grace))
+        .collect();
+    let mut samples = 0;
+
+    let mut pager = RangePager::new(server_id, start, end, REPLAY_PAGE_SIZE);
+    while let Some(range) = pager.next_range() {
+        let rows = pager.advance(backend.query_range(range).await?);
+
+        samples += rows.len();
+        for replay in &mut replays {
+            rows.iter().for_each(|row| replay.feed(row));
+        }
+    }
+
+    let results = replays
+        .into_iter()
+        .map(|replay| LimitReplay {
+            limit: replay.limit,
+            alerts: replay.alerts,
+        })
+        .collect();
+
+    Ok(ReplayReport {
+        server_id: server_id.to_string(),
+        start,
+        end,
+        samples,
+        results,
+    })
+}
+
+/// Parse a `--since` value: RFC 3339 timestamp or relative duration (`30m`, `24h`, `7d`)
+pub fn parse_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in '{value}' (use m, h or d)"))?;
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration '{value}'"))?;
+
+    let duration = match unit {
+        "m" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        _ => {
+            return Err(format!(
+                "unknown unit '{unit}' in '{value}' (use m, h or d)"
+            ));
+        }
+    };
+
+    Ok(now - duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServerMetrics;
+
+    fn rows_with_cpu(values: &[f32]) -> Vec<MetricRow> {
+        let base = Utc::now() - Duration::hours(1);
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, cpu)| {
+                let mut metrics = ServerMetrics::default();
+                metrics.cpus.average_usage = *cpu;
+                MetricRow::from_server_metrics(
+                    "test:3000".to_string(),
+                    "Test".to_string(),
+                    base + Duration::minutes(i as i64),
+                    &metrics,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_limit() {
+        let limit: ReplayLimit = "cpu=80".parse().unwrap();
+        assert_eq!(limit.resource, ReplayResource::Cpu);
+        assert_eq!(limit.limit, 80.0);
+
+        let limit: ReplayLimit = "temp=72.5".parse().unwrap();
+        assert_eq!(limit.resource, ReplayResource::Temperature);
+        assert_eq!(limit.limit, 72.5);
+
+        assert!("disk=80".parse::<ReplayLimit>().is_err());
+        assert!("cpu".parse::<ReplayLimit>().is_err());
+        assert!("cpu=high".parse::<ReplayLimit>().is_err());
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let now = Utc::now();
+        assert_eq!(parse_since("24h", now).unwrap(), now - Duration::hours(24));
+        assert_eq!(parse_since("7d", now).unwrap(), now - Duration::days(7));
+        assert_eq!(
+            parse_since("2025-01-01T00:00:00Z", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2025-01-01T00:00:00+00:00"
+        );
+        assert!(parse_since("7w", now).is_err());
+        assert!(parse_since("yesterday", now).is_err());
+    }
+
+    #[test]
+    fn test_replay_respects_grace() {
+        let limit: ReplayLimit = "cpu=80".parse().unwrap();
+        // Spike of 2 samples, then a sustained run of 4
+        let rows = rows_with_cpu(&[50.0, 90.0, 90.0, 50.0, 85.0, 85.0, 85.0, 85.0, 40.0]);
+
+        // Grace 0: both runs fire
+        assert_eq!(replay_alerts(&rows, &limit, 0).len(), 2);
+
+        // Grace 2: only the sustained run fires (on its third sample)
+        let alerts = replay_alerts(&rows, &limit, 2);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].fired_at, rows[6].timestamp);
+        assert_eq!(alerts[0].recovered_at, Some(rows[8].timestamp));
+
+        // Grace 4: nothing fires
+        assert!(replay_alerts(&rows, &limit, 4).is_empty());
+    }
+
+    #[test]
+    fn test_replay_skips_missing_values() {
+        let limit: ReplayLimit = "temp=70".parse().unwrap();
+        let rows = rows_with_cpu(&[90.0, 90.0]); // No temperature readings
+
+        assert!(replay_alerts(&rows, &limit, 0).is_empty());
+    }
+}
@@ -42,9 +42,19 @@ impl ResourceEvaluation {
         grace: usize,
         current_grace: usize,
     ) -> ResourceEvaluation {
-        // check, if we are under the limit
-        if resource < limit {
-            // if we are now under the limit but the grace period has been exceeded, send
+        Self::evaluate_condition(resource >= limit, grace, current_grace)
+    }
+
+    /// Evaluate a condition that has no single numeric limit (e.g. a trend)
+    /// with the same grace period semantics as [`ResourceEvaluation::evaluate`]
+    pub fn evaluate_condition(
+        violated: bool,
+        grace: usize,
+        current_grace: usize,
+    ) -> ResourceEvaluation {
+        // check, if we are within the limit
+        if !violated {
+            // if we are now within the limit but the grace period has been exceeded, send
             // notification that it is now okay
             if current_grace > grace {
                 return ResourceEvaluation::BackToOk;
@@ -25,6 +25,72 @@ pub struct QueryRange {
     pub limit: Option<usize>,
 }
 
+/// Pages through a server's metrics within a time range
+///
+/// The cursor is inclusive, so each page starts at the previous page's last
+/// timestamp. Timestamps are unique per server, so only that row comes back
+/// again and is dropped. Only builds the queries, so it works against a
+/// `StorageBackend` as well as the storage actor.
+#[derive(Debug, Clone)]
+pub struct RangePager {
+    server_id: String,
+    cursor: DateTime<Utc>,
+    end: DateTime<Utc>,
+    page_size: usize,
+    last_seen: Option<DateTime<Utc>>,
+    done: bool,
+}
+
+impl RangePager {
+    pub fn new(
+        server_id: impl Into<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        page_size: usize,
+    ) -> Self {
+        Self {
+            server_id: server_id.into(),
+            cursor: start,
+            end,
+            page_size,
+            last_seen: None,
+            done: false,
+        }
+    }
+
+    /// Query for the next page (`None` once the range is exhausted)
+    pub fn next_range(&self) -> Option<QueryRange> {
+        (!self.done).then(|| QueryRange {
+            server_id: self.server_id.clone(),
+            start: self.cursor,
+            end: self.end,
+            limit: Some(self.page_size),
+        })
+    }
+
+    /// Advance past a page returned for [`Self::next_range`], returning the
+    /// rows not seen before
+    pub fn advance(&mut self, page: Vec<MetricRow>) -> Vec<MetricRow> {
+        let full = page.len() >= self.page_size;
+        let last_seen = self.last_seen;
+        let rows: Vec<MetricRow> = page
+            .into_iter()
+            .filter(|row| last_seen.is_none_or(|seen| row.timestamp > seen))
+            .collect();
+
+        match rows.last() {
+            Some(last) => {
+                self.cursor = last.timestamp;
+                self.last_seen = Some(last.timestamp);
+                self.done = !full;
+            }
+            None => self.done = true,
+        }
+
+        rows
+    }
+}
+
 /// Health status of the storage backend
 #[derive(Debug, Clone)]
 pub struct HealthStatus {
@@ -38,6 +104,16 @@ pub struct HealthStatus {
     pub metadata: std::collections::HashMap<String, String>,
 }
 
+/// Number of rows removed by a bulk deletion
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeleteCounts {
+    /// Metrics deleted
+    pub metrics: usize,
+
+    /// Service checks deleted (0 if service checks were not included)
+    pub service_checks: usize,
+}
+
 /// Trait for persistent storage backends
 ///
 /// All storage backends (SQLite, PostgreSQL, Parquet, etc.) must
@@ -182,6 +258,41 @@ pub trait StorageBackend: Send + Sync {
     /// Returns the number of checks deleted.
     async fn cleanup_old_service_checks(&self, before: DateTime<Utc>) -> StorageResult<usize>;
 
+    /// Compact service checks into runs of identical status
+    ///
+    /// Collapses consecutive checks before `before` into run-length records
+    /// (status, start, end, count). Compaction is incremental: checks already
+    /// covered by a run are skipped, and a run is extended when newer checks
+    /// continue it. Raw checks are left untouched for retention cleanup.
+    ///
+    /// Returns the number of raw checks that were compacted.
+    async fn compact_service_checks(&self, before: DateTime<Utc>) -> StorageResult<usize>;
+
+    /// Query compacted runs overlapping a time range
+    ///
+    /// Results are ordered by start time (oldest first). The end of the last
+    /// run is the compaction watermark: raw checks after it are not compacted
+    /// yet.
+    async fn query_service_check_runs(
+        &self,
+        service_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> StorageResult<Vec<crate::storage::schema::ServiceCheckRun>>;
+
+    /// Delete all metrics (and optionally service checks) before a timestamp
+    ///
+    /// On-demand bulk purge across all servers, e.g. for compliance requests.
+    /// Unlike retention cleanup, the cutoff is explicit and both deletions
+    /// happen atomically. With service checks, compacted runs before the cutoff
+    /// are deleted as well, and runs straddling it are clipped to start at the
+    /// cutoff (with their check count scaled down accordingly).
+    async fn delete_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        include_service_checks: bool,
+    ) -> StorageResult<DeleteCounts>;
+
     /// Close the backend and release resources
     ///
     /// Gracefully shuts down the backend, closing connections
@@ -18,9 +18,9 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use tracing::debug;
 
-use super::backend::{HealthStatus, QueryRange, StorageBackend};
+use super::backend::{DeleteCounts, HealthStatus, QueryRange, StorageBackend};
 use super::error::StorageResult;
-use super::schema::{MetricRow, ServiceCheckRow, UptimeStats};
+use super::schema::{MetricRow, ServiceCheckRow, ServiceCheckRun, UptimeStats};
 use crate::actors::messages::ServiceStatus;
 
 /// In-memory storage backend
@@ -248,6 +248,35 @@ impl StorageBackend for MemoryBackend {
         Ok(0)
     }
 
+    async fn compact_service_checks(&self, before: DateTime<Utc>) -> StorageResult<usize> {
+        debug!("compaction requested for service checks before {}", before);
+        // Raw checks are bounded by the ring buffer, nothing to compact
+        Ok(0)
+    }
+
+    async fn query_service_check_runs(
+        &self,
+        service_name: &str,
+        _start: DateTime<Utc>,
+        _end: DateTime<Utc>,
+    ) -> StorageResult<Vec<ServiceCheckRun>> {
+        debug!("querying in-memory service check runs for {}", service_name);
+        Ok(Vec::new())
+    }
+
+    async fn delete_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        include_service_checks: bool,
+    ) -> StorageResult<DeleteCounts> {
+        debug!(
+            "deletion requested for data before {} (service checks: {})",
+            cutoff, include_service_checks
+        );
+        // Would need interior mutability
+        Ok(DeleteCounts::default())
+    }
+
     async fn close(&self) -> StorageResult<()> {
         debug!("closing in-memory backend (no-op)");
         Ok(())
@@ -9,6 +9,7 @@
 //! - `cpu_avg` - Average CPU usage across all cores
 //! - `memory_used`, `memory_total` - Memory statistics
 //! - `temp_avg` - Average temperature across components
+//! - `collection_ms` - Agent poll duration
 //!
 //! ### Detailed Metrics (JSON)
 //! Store detailed breakdowns as JSON for flexibility:
@@ -65,6 +66,10 @@ pub struct MetricRow {
     /// Average temperature across all components (Celsius)
     pub temp_avg: Option<f32>,
 
+    /// How long polling the agent took (milliseconds, None for imported data)
+    #[serde(default)]
+    pub collection_ms: Option<u64>,
+
     // === Detailed metrics (full ServerMetrics struct) ===
     /// Complete ServerMetrics structure containing all detailed data
     ///
@@ -118,6 +123,7 @@ impl MetricRow {
             memory_used,
             memory_total,
             temp_avg,
+            collection_ms: None,
             metadata: metrics.clone(), // Store the complete struct directly
         }
     }
@@ -153,6 +159,14 @@ pub struct ServiceCheckRow {
 
     /// Error message (if check failed)
     pub error_message: Option<String>,
+
+    /// Request body size in bytes (if request was sent)
+    #[serde(default)]
+    pub request_bytes: Option<u64>,
+
+    /// Response body size in bytes (if response was received)
+    #[serde(default)]
+    pub response_bytes: Option<u64>,
 }
 
 impl ServiceCheckRow {
@@ -166,7 +180,50 @@ impl ServiceCheckRow {
             response_time_ms: event.response_time_ms,
             http_status_code: event.http_status_code,
             error_message: event.error_message.clone(),
+            request_bytes: event.request_bytes,
+            response_bytes: event.response_bytes,
+        }
+    }
+}
+
+/// A run of consecutive service checks with identical status
+///
+/// Produced by compaction: e.g. 10,000 consecutive `Up` checks become a
+/// single run, which keeps long-range uptime and incident queries cheap.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceCheckRun {
+    /// Service name (from configuration)
+    pub service_name: String,
+
+    /// Status shared by all checks in the run
+    pub status: ServiceStatus,
+
+    /// Timestamp of the first check in the run
+    pub start: DateTime<Utc>,
+
+    /// Timestamp of the last check in the run
+    pub end: DateTime<Utc>,
+
+    /// Number of checks in the run
+    pub count: usize,
+}
+
+impl ServiceCheckRun {
+    /// Number of checks in the run at or after `since`
+    ///
+    /// Checks in a run are evenly spaced, so for a run straddling `since`
+    /// this is the share of its intervals after `since` (plus its last check).
+    pub fn count_since(&self, since: DateTime<Utc>) -> usize {
+        if self.start >= since {
+            return self.count;
         }
+        if self.end < since {
+            return 0;
+        }
+
+        let remaining = (self.end - since).num_milliseconds() as f64;
+        let total = (self.end - self.start).num_milliseconds() as f64;
+        1 + ((self.count - 1) as f64 * remaining / total) as usize
     }
 }
 
@@ -31,9 +31,9 @@ use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, S
 use sqlx::{Pool, Row, Sqlite};
 use tracing::{debug, info, instrument, warn};
 
-use super::backend::{HealthStatus, QueryRange, StorageBackend};
+use super::backend::{DeleteCounts, HealthStatus, QueryRange, StorageBackend};
 use super::error::{StorageError, StorageResult};
-use super::schema::{MetricRow, MetricType, ServiceCheckRow, UptimeStats};
+use super::schema::{MetricRow, MetricType, ServiceCheckRow, ServiceCheckRun, UptimeStats};
 use crate::actors::messages::ServiceStatus;
 
 /// SQLite storage backend
@@ -144,9 +144,9 @@ impl StorageBackend for SqliteBackend {
                 r#"
                 INSERT INTO metrics (
                     server_id, timestamp, display_name, metric_type,
-                    cpu_avg, memory_used, memory_total, temp_avg, metadata
+                    cpu_avg, memory_used, memory_total, temp_avg, collection_ms, metadata
                 )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 ON CONFLICT (server_id, timestamp) DO UPDATE SET
                     display_name = excluded.display_name,
                     metric_type = excluded.metric_type,
@@ -154,6 +154,7 @@ impl StorageBackend for SqliteBackend {
                     memory_used = excluded.memory_used,
                     memory_total = excluded.memory_total,
                     temp_avg = excluded.temp_avg,
+                    collection_ms = excluded.collection_ms,
                     metadata = excluded.metadata
                 "#,
             )
@@ -165,6 +166,7 @@ impl StorageBackend for SqliteBackend {
             .bind(metric.memory_used.map(|v| v as i64))
             .bind(metric.memory_total.map(|v| v as i64))
             .bind(metric.temp_avg)
+            .bind(metric.collection_ms.map(|v| v as i64))
             .bind(metadata_json)
             .execute(&mut *tx)
             .await
@@ -197,7 +199,7 @@ impl StorageBackend for SqliteBackend {
         let sql = format!(
             r#"
             SELECT server_id, timestamp, display_name, metric_type,
-                   cpu_avg, memory_used, memory_total, temp_avg, metadata
+                   cpu_avg, memory_used, memory_total, temp_avg, collection_ms, metadata
             FROM metrics
             WHERE server_id = ? AND timestamp >= ? AND timestamp <= ?
             ORDER BY timestamp ASC
@@ -245,6 +247,7 @@ impl StorageBackend for SqliteBackend {
                     memory_used: row.get::<Option<i64>, _>("memory_used").map(|v| v as u64),
                     memory_total: row.get::<Option<i64>, _>("memory_total").map(|v| v as u64),
                     temp_avg: row.get("temp_avg"),
+                    collection_ms: row.get::<Option<i64>, _>("collection_ms").map(|v| v as u64),
                     metadata,
                 })
             })
@@ -262,7 +265,7 @@ impl StorageBackend for SqliteBackend {
         let rows = sqlx::query(
             r#"
             SELECT server_id, timestamp, display_name, metric_type,
-                   cpu_avg, memory_used, memory_total, temp_avg, metadata
+                   cpu_avg, memory_used, memory_total, temp_avg, collection_ms, metadata
             FROM metrics
             WHERE server_id = ?
             ORDER BY timestamp DESC
@@ -306,6 +309,7 @@ impl StorageBackend for SqliteBackend {
                     memory_used: row.get::<Option<i64>, _>("memory_used").map(|v| v as u64),
                     memory_total: row.get::<Option<i64>, _>("memory_total").map(|v| v as u64),
                     temp_avg: row.get("temp_avg"),
+                    collection_ms: row.get::<Option<i64>, _>("collection_ms").map(|v| v as u64),
                     metadata,
                 })
             })
@@ -438,8 +442,9 @@ impl StorageBackend for SqliteBackend {
             sqlx::query(
                 r#"
                 INSERT INTO service_checks
-                (service_name, timestamp, url, status, response_time_ms, http_status_code, error_message)
-                VALUES (?, ?, ?, ?, ?, ?, ?)
+                (service_name, timestamp, url, status, response_time_ms, http_status_code, error_message,
+                 request_bytes, response_bytes)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&check.service_name)
@@ -449,6 +454,8 @@ impl StorageBackend for SqliteBackend {
             .bind(check.response_time_ms.map(|v| v as i64))
             .bind(check.http_status_code.map(|v| v as i64))
             .bind(&check.error_message)
+            .bind(check.request_bytes.map(|v| v as i64))
+            .bind(check.response_bytes.map(|v| v as i64))
             .execute(&mut *tx)
             .await
             .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
@@ -479,7 +486,8 @@ impl StorageBackend for SqliteBackend {
 
         let rows = sqlx::query(
             r#"
-            SELECT service_name, timestamp, url, status, response_time_ms, http_status_code, error_message
+            SELECT service_name, timestamp, url, status, response_time_ms, http_status_code, error_message,
+                   request_bytes, response_bytes
             FROM service_checks
             WHERE service_name = ? AND timestamp >= ? AND timestamp <= ?
             ORDER BY timestamp ASC
@@ -516,6 +524,10 @@ impl StorageBackend for SqliteBackend {
                         .get::<Option<i64>, _>("http_status_code")
                         .map(|v| v as u16),
                     error_message: row.get("error_message"),
+                    request_bytes: row.get::<Option<i64>, _>("request_bytes").map(|v| v as u64),
+                    response_bytes: row
+                        .get::<Option<i64>, _>("response_bytes")
+                        .map(|v| v as u64),
                 })
             })
             .collect();
@@ -536,7 +548,8 @@ impl StorageBackend for SqliteBackend {
 
         let rows = sqlx::query(
             r#"
-            SELECT service_name, timestamp, url, status, response_time_ms, http_status_code, error_message
+            SELECT service_name, timestamp, url, status, response_time_ms, http_status_code, error_message,
+                   request_bytes, response_bytes
             FROM service_checks
             WHERE service_name = ?
             ORDER BY timestamp DESC
@@ -573,6 +586,10 @@ impl StorageBackend for SqliteBackend {
                         .get::<Option<i64>, _>("http_status_code")
                         .map(|v| v as u16),
                     error_message: row.get("error_message"),
+                    request_bytes: row.get::<Option<i64>, _>("request_bytes").map(|v| v as u64),
+                    response_bytes: row
+                        .get::<Option<i64>, _>("response_bytes")
+                        .map(|v| v as u64),
                 })
             })
             .collect();
@@ -647,6 +664,253 @@ impl StorageBackend for SqliteBackend {
         Ok(deleted)
     }
 
+    #[instrument(skip(self), fields(before = %before))]
+    async fn compact_service_checks(&self, before: DateTime<Utc>) -> StorageResult<usize> {
+        let before_millis = Self::timestamp_to_millis(&before);
+
+        debug!("compacting service checks older than {}", before);
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        // Latest run per service: the compaction watermark, and the run that
+        // newer checks may continue
+        let latest_runs = sqlx::query(
+            r#"
+            SELECT service_name, status, start_time, end_time, check_count
+            FROM service_check_runs AS r
+            WHERE end_time = (
+                SELECT MAX(end_time) FROM service_check_runs WHERE service_name = r.service_name
+            )
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        // (status, start, end, count) of the open run per service
+        let mut open_runs: HashMap<String, (String, i64, i64, i64)> = latest_runs
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get("service_name"),
+                    (
+                        row.get("status"),
+                        row.get("start_time"),
+                        row.get("end_time"),
+                        row.get("check_count"),
+                    ),
+                )
+            })
+            .collect();
+
+        // Only checks newer than the service's watermark (its latest run end)
+        let checks = sqlx::query(
+            r#"
+            SELECT service_name, timestamp, status
+            FROM service_checks AS c
+            WHERE timestamp < ?
+              AND timestamp > COALESCE(
+                  (SELECT MAX(end_time) FROM service_check_runs WHERE service_name = c.service_name),
+                  -1
+              )
+            ORDER BY service_name, timestamp ASC
+            "#,
+        )
+        .bind(before_millis)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        let mut runs: Vec<(String, (String, i64, i64, i64))> = Vec::new();
+        let mut compacted = 0;
+
+        for check in checks {
+            let service_name: String = check.get("service_name");
+            let timestamp: i64 = check.get("timestamp");
+            let status: String = check.get("status");
+            compacted += 1;
+
+            match open_runs.get_mut(&service_name) {
+                Some(run) if run.0 == status => {
+                    run.2 = timestamp;
+                    run.3 += 1;
+                }
+                _ => {
+                    // Status changed: the previous run is complete
+                    if let Some(run) =
+                        open_runs.insert(service_name.clone(), (status, timestamp, timestamp, 1))
+                    {
+                        runs.push((service_name, run));
+                    }
+                }
+            }
+        }
+        runs.extend(open_runs);
+
+        for (service_name, (status, start, end, count)) in runs {
+            // Untouched runs are rewritten unchanged
+            sqlx::query(
+                r#"
+                INSERT OR REPLACE INTO service_check_runs
+                (service_name, status, start_time, end_time, check_count)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&service_name)
+            .bind(&status)
+            .bind(start)
+            .bind(end)
+            .bind(count)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        debug!("compacted {} service checks", compacted);
+
+        Ok(compacted)
+    }
+
+    #[instrument(skip(self), fields(service_name))]
+    async fn query_service_check_runs(
+        &self,
+        service_name: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> StorageResult<Vec<ServiceCheckRun>> {
+        let start_millis = Self::timestamp_to_millis(&start);
+        let end_millis = Self::timestamp_to_millis(&end);
+
+        debug!(
+            "querying service check runs for {} from {} to {}",
+            service_name, start, end
+        );
+
+        let rows = sqlx::query(
+            r#"
+            SELECT service_name, status, start_time, end_time, check_count
+            FROM service_check_runs
+            WHERE service_name = ? AND end_time >= ? AND start_time <= ?
+            ORDER BY start_time ASC
+            "#,
+        )
+        .bind(service_name)
+        .bind(start_millis)
+        .bind(end_millis)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        let runs = rows
+            .into_iter()
+            .map(|row| {
+                let status_str: String = row.get("status");
+                let status = match status_str.as_str() {
+                    "up" => ServiceStatus::Up,
+                    "down" => ServiceStatus::Down,
+                    "degraded" => ServiceStatus::Degraded,
+                    _ => ServiceStatus::Down,
+                };
+
+                ServiceCheckRun {
+                    service_name: row.get("service_name"),
+                    status,
+                    start: Self::millis_to_timestamp(row.get("start_time")),
+                    end: Self::millis_to_timestamp(row.get("end_time")),
+                    count: row.get::<i64, _>("check_count") as usize,
+                }
+            })
+            .collect();
+
+        Ok(runs)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        include_service_checks: bool,
+    ) -> StorageResult<DeleteCounts> {
+        let cutoff_millis = Self::timestamp_to_millis(&cutoff);
+
+        info!(
+            "deleting data before {} (service checks: {})",
+            cutoff, include_service_checks
+        );
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        let metrics = sqlx::query("DELETE FROM metrics WHERE timestamp < ?")
+            .bind(cutoff_millis)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?
+            .rows_affected() as usize;
+
+        let service_checks = if include_service_checks {
+            // Compacted runs ending before the cutoff hold the same history
+            sqlx::query("DELETE FROM service_check_runs WHERE end_time < ?")
+                .bind(cutoff_millis)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+            // Runs straddling the cutoff are clipped to start at it. Checks in
+            // a run are evenly spaced, so the remaining count is the share of
+            // the run's intervals after the cutoff (plus its last check).
+            sqlx::query(
+                r#"
+                UPDATE service_check_runs
+                SET check_count = 1 + CAST(
+                        (check_count - 1) * (end_time - ?1) * 1.0 / (end_time - start_time)
+                        AS INTEGER
+                    ),
+                    start_time = ?1
+                WHERE start_time < ?1 AND end_time >= ?1
+                "#,
+            )
+            .bind(cutoff_millis)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+            sqlx::query("DELETE FROM service_checks WHERE timestamp < ?")
+                .bind(cutoff_millis)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| StorageError::QueryFailed(e.to_string()))?
+                .rows_affected() as usize
+        } else {
+            0
+        };
+
+        tx.commit()
+            .await
+            .map_err(|e| StorageError::QueryFailed(e.to_string()))?;
+
+        info!(
+            "deleted {} metrics and {} service checks",
+            metrics, service_checks
+        );
+
+        Ok(DeleteCounts {
+            metrics,
+            service_checks,
+        })
+    }
+
     async fn close(&self) -> StorageResult<()> {
         info!("closing SQLite backend");
         self.pool.close().await;
@@ -838,6 +1102,303 @@ mod tests {
         assert_eq!(remaining.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_delete_before() {
+        use crate::actors::messages::{ServiceCheckEvent, ServiceStatus};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+        let metrics = create_test_metrics();
+        // Millisecond precision, as stored
+        let now = DateTime::from_timestamp_millis(Utc::now().timestamp_millis()).unwrap();
+        let cutoff = now - Duration::days(3);
+
+        // Two servers, samples spread across 6 days (3 before the cutoff each)
+        let servers = ["192.168.1.100:3000", "192.168.1.101:3000"];
+        let mut rows = Vec::new();
+        let mut checks = Vec::new();
+        for days in 0..6 {
+            let timestamp = now - Duration::days(days) - Duration::minutes(1);
+            for server_id in servers {
+                rows.push(MetricRow::from_server_metrics(
+                    server_id.to_string(),
+                    "Test Server".to_string(),
+                    timestamp,
+                    &metrics,
+                ));
+            }
+            checks.push(ServiceCheckRow::from_event(&ServiceCheckEvent {
+                service_name: "api".to_string(),
+                url: "https://api.example.com".to_string(),
+                timestamp,
+                status: ServiceStatus::Up,
+                response_time_ms: Some(10),
+                http_status_code: Some(200),
+                ssl_expiry_days: None,
+                error_message: None,
+                request_bytes: Some(0),
+                response_bytes: Some(2),
+            }));
+        }
+        backend.insert_batch(rows).await.unwrap();
+        backend.insert_service_checks_batch(checks).await.unwrap();
+
+        // Metrics only
+        let counts = backend.delete_before(cutoff, false).await.unwrap();
+        assert_eq!(
+            counts,
+            DeleteCounts {
+                metrics: 6,
+                service_checks: 0
+            }
+        );
+
+        for server_id in servers {
+            let remaining = backend.query_latest(server_id, 10).await.unwrap();
+            assert_eq!(remaining.len(), 3);
+            assert!(remaining.iter().all(|row| row.timestamp >= cutoff));
+        }
+
+        let all_time = now - Duration::days(30);
+        let remaining_checks = backend
+            .query_service_checks_range("api", all_time, now + Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(remaining_checks.len(), 6);
+
+        // Including service checks
+        let counts = backend.delete_before(cutoff, true).await.unwrap();
+        assert_eq!(
+            counts,
+            DeleteCounts {
+                metrics: 0,
+                service_checks: 3
+            }
+        );
+
+        let remaining_checks = backend
+            .query_service_checks_range("api", all_time, now + Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(remaining_checks.len(), 3);
+        assert!(
+            remaining_checks
+                .iter()
+                .all(|check| check.timestamp >= cutoff)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compact_service_checks() {
+        use crate::actors::messages::ServiceCheckEvent;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+        // Millisecond precision, as stored
+        let now = DateTime::from_timestamp_millis(Utc::now().timestamp_millis()).unwrap();
+        let start = now - Duration::days(2);
+        let check = |minutes: i64, status: ServiceStatus| {
+            ServiceCheckRow::from_event(&ServiceCheckEvent {
+                service_name: "api".to_string(),
+                url: "https://api.example.com".to_string(),
+                timestamp: start + Duration::minutes(minutes),
+                status,
+                response_time_ms: Some(10),
+                http_status_code: Some(200),
+                ssl_expiry_days: None,
+                error_message: None,
+                request_bytes: Some(0),
+                response_bytes: Some(2),
+            })
+        };
+
+        // A long up-run compacts into a single record
+        let checks = (0..1000).map(|m| check(m, ServiceStatus::Up)).collect();
+        backend.insert_service_checks_batch(checks).await.unwrap();
+
+        let compacted = backend.compact_service_checks(now).await.unwrap();
+        assert_eq!(compacted, 1000);
+
+        let runs = backend
+            .query_service_check_runs("api", start, now)
+            .await
+            .unwrap();
+        assert_eq!(
+            runs,
+            vec![ServiceCheckRun {
+                service_name: "api".to_string(),
+                status: ServiceStatus::Up,
+                start,
+                end: start + Duration::minutes(999),
+                count: 1000,
+            }]
+        );
+
+        // Re-running without new checks is a no-op
+        assert_eq!(backend.compact_service_checks(now).await.unwrap(), 0);
+
+        // New checks continue the open run, then an outage starts a new one
+        let checks = (1000..1010)
+            .map(|m| check(m, ServiceStatus::Up))
+            .chain((1010..1015).map(|m| check(m, ServiceStatus::Down)))
+            .chain((1015..1100).map(|m| check(m, ServiceStatus::Up)))
+            .collect();
+        backend.insert_service_checks_batch(checks).await.unwrap();
+
+        let compacted = backend.compact_service_checks(now).await.unwrap();
+        assert_eq!(compacted, 100);
+
+        let runs = backend
+            .query_service_check_runs("api", start, now)
+            .await
+            .unwrap();
+        let summary: Vec<_> = runs.iter().map(|run| (run.status, run.count)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ServiceStatus::Up, 1010),
+                (ServiceStatus::Down, 5),
+                (ServiceStatus::Up, 85),
+            ]
+        );
+
+        // Uptime from compacted runs matches uptime from raw checks, also
+        // for a range starting inside the first run
+        for since in [start - Duration::hours(1), start + Duration::minutes(500)] {
+            let raw = backend.calculate_uptime("api", since).await.unwrap();
+            let runs = backend
+                .query_service_check_runs("api", since, now)
+                .await
+                .unwrap();
+            let total: usize = runs.iter().map(|run| run.count_since(since)).sum();
+            let successful: usize = runs
+                .iter()
+                .filter(|run| run.status == ServiceStatus::Up)
+                .map(|run| run.count_since(since))
+                .sum();
+            assert_eq!(total, raw.total_checks);
+            assert_eq!(successful, raw.successful_checks);
+        }
+        assert_eq!(
+            backend
+                .calculate_uptime("api", start - Duration::hours(1))
+                .await
+                .unwrap()
+                .total_checks,
+            1100
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compaction_skips_checks_below_watermark() {
+        use crate::actors::messages::ServiceCheckEvent;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+        let now = DateTime::from_timestamp_millis(Utc::now().timestamp_millis()).unwrap();
+        let start = now - Duration::hours(10);
+        let check = |seconds: i64| {
+            ServiceCheckRow::from_event(&ServiceCheckEvent {
+                service_name: "api".to_string(),
+                url: "https://api.example.com".to_string(),
+                timestamp: start + Duration::seconds(seconds),
+                status: ServiceStatus::Up,
+                response_time_ms: Some(10),
+                http_status_code: Some(200),
+                ssl_expiry_days: None,
+                error_message: None,
+                request_bytes: None,
+                response_bytes: Some(2),
+            })
+        };
+
+        // One check per minute
+        backend
+            .insert_service_checks_batch((0..100).map(|m| check(m * 60)).collect())
+            .await
+            .unwrap();
+        assert_eq!(backend.compact_service_checks(now).await.unwrap(), 100);
+
+        // A late check behind the watermark is not folded in
+        let late = vec![check(50 * 60 + 30)];
+        let newer = (100..110).map(|m| check(m * 60));
+        backend
+            .insert_service_checks_batch(late.into_iter().chain(newer).collect())
+            .await
+            .unwrap();
+        assert_eq!(backend.compact_service_checks(now).await.unwrap(), 10);
+
+        let runs = backend
+            .query_service_check_runs("api", start, now)
+            .await
+            .unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].count, 110);
+    }
+
+    #[tokio::test]
+    async fn test_delete_before_clips_straddling_runs() {
+        use crate::actors::messages::ServiceCheckEvent;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+        let now = DateTime::from_timestamp_millis(Utc::now().timestamp_millis()).unwrap();
+        let start = now - Duration::hours(10);
+        let check = |minutes: i64, status: ServiceStatus| {
+            ServiceCheckRow::from_event(&ServiceCheckEvent {
+                service_name: "api".to_string(),
+                url: "https://api.example.com".to_string(),
+                timestamp: start + Duration::minutes(minutes),
+                status,
+                response_time_ms: Some(10),
+                http_status_code: Some(200),
+                ssl_expiry_days: None,
+                error_message: None,
+                request_bytes: None,
+                response_bytes: Some(2),
+            })
+        };
+
+        // Down for 20 minutes, then up for 100
+        let checks = (0..20)
+            .map(|m| check(m, ServiceStatus::Down))
+            .chain((20..120).map(|m| check(m, ServiceStatus::Up)))
+            .collect();
+        backend.insert_service_checks_batch(checks).await.unwrap();
+        backend.compact_service_checks(now).await.unwrap();
+
+        // Cut in the middle of the up-run
+        let cutoff = start + Duration::minutes(70);
+        backend.delete_before(cutoff, true).await.unwrap();
+
+        let runs = backend
+            .query_service_check_runs("api", start - Duration::hours(1), now)
+            .await
+            .unwrap();
+        assert_eq!(
+            runs,
+            vec![ServiceCheckRun {
+                service_name: "api".to_string(),
+                status: ServiceStatus::Up,
+                start: cutoff,
+                end: start + Duration::minutes(119),
+                count: 50,
+            }]
+        );
+
+        // The clipped run agrees with the raw checks that are left
+        let raw = backend.calculate_uptime("api", cutoff).await.unwrap();
+        assert_eq!(raw.total_checks, 50);
+    }
+
     #[tokio::test]
     async fn test_health_check() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -49,7 +49,7 @@ impl App {
         })?;
 
         let mut app = Self {
-            state: AppState::new(config.time_window_seconds),
+            state: AppState::new(config.time_window_seconds, config.chart_scale),
             config,
             ws_rx,
             http_client,
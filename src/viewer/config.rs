@@ -25,11 +25,26 @@ pub struct Config {
     #[serde(default = "default_time_window")]
     pub time_window_seconds: u64,
 
+    /// Chart Y-axis scaling mode (default: fixed)
+    #[serde(default)]
+    pub chart_scale: ChartScale,
+
     /// Enable debug mode (default: false)
     #[serde(default)]
     pub debug: bool,
 }
 
+/// Y-axis scaling mode for charts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartScale {
+    /// Fixed scale starting at zero (e.g. 0-100% for CPU)
+    #[default]
+    Fixed,
+    /// Fit the axis to the data range in the current time window
+    Auto,
+}
+
 fn default_refresh_interval() -> u64 {
     5
 }
@@ -77,6 +92,7 @@ impl Default for Config {
             refresh_interval: default_refresh_interval(),
             max_metrics: default_max_metrics(),
             time_window_seconds: default_time_window(),
+            chart_scale: ChartScale::default(),
             debug: false,
         }
     }
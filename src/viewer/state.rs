@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use std::collections::{HashMap, VecDeque};
 
 use crate::ServerMetrics;
+use crate::viewer::config::ChartScale;
 
 #[cfg(feature = "api")]
 use crate::api::{ServerInfo, ServiceInfo};
@@ -114,12 +115,15 @@ pub struct AppState {
     /// Chart time window in seconds (for sliding window display)
     pub time_window_seconds: u64,
 
+    /// Chart Y-axis scaling mode
+    pub chart_scale: ChartScale,
+
     /// Number of data points to load (calculated from terminal width)
     pub data_limit: usize,
 }
 
 impl AppState {
-    pub fn new(time_window_seconds: u64) -> Self {
+    pub fn new(time_window_seconds: u64, chart_scale: ChartScale) -> Self {
         Self {
             current_tab: Tab::Servers,
             servers: Vec::new(),
@@ -134,6 +138,7 @@ impl AppState {
             connected: false,
             error_message: None,
             time_window_seconds,
+            chart_scale,
             data_limit: 100, // Default, will be updated based on terminal size
         }
     }
@@ -289,6 +294,6 @@ impl AppState {
 
 impl Default for AppState {
     fn default() -> Self {
-        Self::new(300) // Default 5 minute window
+        Self::new(300, ChartScale::default()) // Default 5 minute window
     }
 }
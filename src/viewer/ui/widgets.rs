@@ -11,7 +11,7 @@ use ratatui::{
     },
 };
 
-use crate::viewer::state::AppState;
+use crate::viewer::{config::ChartScale, state::AppState};
 
 /// Color palette for multiple lines (cores, components, etc.)
 const LINE_COLORS: [Color; 16] = [
@@ -36,6 +36,64 @@ const LINE_COLORS: [Color; 16] = [
 /// Minimum width for legend area
 const MIN_LEGEND_WIDTH: u16 = 10;
 
+/// Fraction of the data range added above and below in auto scale mode
+const AUTO_SCALE_PADDING: f64 = 0.1;
+
+/// Minimum Y-axis span for percentage charts in auto scale mode
+const MIN_PERCENT_SPAN: f64 = 10.0;
+
+/// Minimum Y-axis span for temperature charts in auto scale mode (°C)
+const MIN_TEMP_SPAN: f64 = 10.0;
+
+/// Minimum Y-axis span for memory charts in auto scale mode (1 GB)
+const MIN_MEMORY_SPAN: f64 = (1u64 << 30) as f64;
+
+/// Compute Y-axis bounds for a chart
+///
+/// - `Fixed`: `[0, fixed_max]`, extended if the data goes above `fixed_max`
+/// - `Auto`: fitted to the data range with padding, at least `min_span` wide
+///   and never below zero
+fn y_axis_bounds(
+    values: impl IntoIterator<Item = f64>,
+    scale: ChartScale,
+    fixed_max: f64,
+    min_span: f64,
+) -> [f64; 2] {
+    let (min, max) = values
+        .into_iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        });
+
+    if min > max {
+        // No data points
+        return [0.0, fixed_max];
+    }
+
+    match scale {
+        ChartScale::Fixed => [0.0, fixed_max.max(max)],
+        ChartScale::Auto => {
+            let padding = (max - min) * AUTO_SCALE_PADDING;
+            let mut lower = (min - padding).max(0.0);
+            let mut upper = max + padding;
+
+            if upper - lower < min_span {
+                let center = (lower + upper) / 2.0;
+                lower = (center - min_span / 2.0).max(0.0);
+                upper = lower + min_span;
+            }
+
+            [lower, upper]
+        }
+    }
+}
+
+/// Create lower/middle/upper labels for Y-axis bounds
+fn y_axis_labels(bounds: [f64; 2], format: impl Fn(f64) -> String) -> Vec<String> {
+    let [lower, upper] = bounds;
+    vec![format(lower), format((lower + upper) / 2.0), format(upper)]
+}
+
 /// Render a side legend with all items (no truncation)
 /// Inspired by bottom's approach - shows all items in a list
 fn render_side_legend(frame: &mut Frame, area: Rect, datasets: &[(String, Color, String)]) {
@@ -134,11 +192,14 @@ pub fn render_cpu_chart(frame: &mut Frame, area: Rect, server_id: &str, state: &
         }
 
         // Calculate bounds
-        let max_cpu = core_data
-            .iter()
-            .flat_map(|data| data.iter().map(|(_, cpu)| *cpu))
-            .fold(0.0, f64::max)
-            .max(100.0);
+        let y_bounds = y_axis_bounds(
+            core_data
+                .iter()
+                .flat_map(|data| data.iter().map(|(_, cpu)| *cpu)),
+            state.chart_scale,
+            100.0,
+            MIN_PERCENT_SPAN,
+        );
 
         // Fixed X-axis bounds: [now - time_window, now]
         let x_min = window_start.timestamp() as f64;
@@ -191,12 +252,8 @@ pub fn render_cpu_chart(frame: &mut Frame, area: Rect, server_id: &str, state: &
             .y_axis(
                 Axis::default()
                     .style(Style::default().fg(Color::Gray))
-                    .labels(vec![
-                        "0".to_string(),
-                        format!("{:.0}", max_cpu / 2.0),
-                        format!("{:.0}", max_cpu),
-                    ])
-                    .bounds([0.0, max_cpu]),
+                    .labels(y_axis_labels(y_bounds, |v| format!("{:.0}", v)))
+                    .bounds(y_bounds),
             );
 
         // Add built-in legend if not using side legend
@@ -237,7 +294,12 @@ fn render_cpu_chart_average(frame: &mut Frame, area: Rect, server_id: &str, stat
 
         data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
 
-        let max_cpu = 100.0;
+        let y_bounds = y_axis_bounds(
+            data.iter().map(|(_, cpu)| *cpu),
+            state.chart_scale,
+            100.0,
+            MIN_PERCENT_SPAN,
+        );
         let x_min = window_start.timestamp() as f64;
         let x_max = now.timestamp() as f64;
 
@@ -272,8 +334,8 @@ fn render_cpu_chart_average(frame: &mut Frame, area: Rect, server_id: &str, stat
             .y_axis(
                 Axis::default()
                     .style(Style::default().fg(Color::Gray))
-                    .labels(vec!["0".to_string(), "50".to_string(), "100".to_string()])
-                    .bounds([0.0, max_cpu]),
+                    .labels(y_axis_labels(y_bounds, |v| format!("{:.0}", v)))
+                    .bounds(y_bounds),
             );
 
         frame.render_widget(chart, area);
@@ -353,12 +415,15 @@ pub fn render_temp_chart(frame: &mut Frame, area: Rect, server_id: &str, state:
             }
         }
 
-        // Calculate max_temp before moving data
-        let max_temp = component_data
-            .values()
-            .flat_map(|data| data.iter().map(|(_, temp)| *temp))
-            .fold(0.0, f64::max)
-            .max(100.0); // Minimum scale of 50°C
+        // Calculate bounds before moving data
+        let y_bounds = y_axis_bounds(
+            component_data
+                .values()
+                .flat_map(|data| data.iter().map(|(_, temp)| *temp)),
+            state.chart_scale,
+            100.0,
+            MIN_TEMP_SPAN,
+        );
 
         // Create datasets by taking references
         let mut datasets = Vec::new();
@@ -423,12 +488,8 @@ pub fn render_temp_chart(frame: &mut Frame, area: Rect, server_id: &str, state:
             .y_axis(
                 Axis::default()
                     .style(Style::default().fg(Color::Gray))
-                    .labels(vec![
-                        "0".to_string(),
-                        format!("{:.0}", max_temp / 2.0),
-                        format!("{:.0}", max_temp),
-                    ])
-                    .bounds([0.0, max_temp]),
+                    .labels(y_axis_labels(y_bounds, |v| format!("{:.0}", v)))
+                    .bounds(y_bounds),
             );
 
         // Add built-in legend if not using side legend
@@ -471,11 +532,12 @@ fn render_temp_chart_average(frame: &mut Frame, area: Rect, server_id: &str, sta
 
         data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
 
-        let max_temp = data
-            .iter()
-            .map(|(_, temp)| *temp)
-            .fold(0.0, f64::max)
-            .max(50.0);
+        let y_bounds = y_axis_bounds(
+            data.iter().map(|(_, temp)| *temp),
+            state.chart_scale,
+            50.0,
+            MIN_TEMP_SPAN,
+        );
 
         let x_min = window_start.timestamp() as f64;
         let x_max = now.timestamp() as f64;
@@ -511,12 +573,8 @@ fn render_temp_chart_average(frame: &mut Frame, area: Rect, server_id: &str, sta
             .y_axis(
                 Axis::default()
                     .style(Style::default().fg(Color::Gray))
-                    .labels(vec![
-                        "0".to_string(),
-                        format!("{:.0}", max_temp / 2.0),
-                        format!("{:.0}", max_temp),
-                    ])
-                    .bounds([0.0, max_temp]),
+                    .labels(y_axis_labels(y_bounds, |v| format!("{:.0}", v)))
+                    .bounds(y_bounds),
             );
 
         frame.render_widget(chart, area);
@@ -564,6 +622,17 @@ pub fn render_memory_chart(frame: &mut Frame, area: Rect, server_id: &str, state
         ram_data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
         swap_data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
 
+        // Fixed mode spans the total capacity, auto mode fits the used values
+        let y_bounds = y_axis_bounds(
+            ram_data
+                .iter()
+                .chain(swap_data.iter())
+                .map(|(_, used)| *used),
+            state.chart_scale,
+            max_memory,
+            MIN_MEMORY_SPAN,
+        );
+
         // Fixed X-axis bounds
         let x_min = window_start.timestamp() as f64;
         let x_max = now.timestamp() as f64;
@@ -612,12 +681,10 @@ pub fn render_memory_chart(frame: &mut Frame, area: Rect, server_id: &str, state
             .y_axis(
                 Axis::default()
                     .style(Style::default().fg(Color::Gray))
-                    .labels(vec![
-                        "0GB".to_string(),
-                        format!("{:.1} GB", max_memory / (2u64.pow(31) as f64)),
-                        format!("{:.1} GB", max_memory / (2u64.pow(30) as f64)),
-                    ])
-                    .bounds([0.0, max_memory]),
+                    .labels(y_axis_labels(y_bounds, |v| {
+                        format!("{:.1} GB", v / (2u64.pow(30) as f64))
+                    }))
+                    .bounds(y_bounds),
             )
             .legend_position(Some(LegendPosition::Bottom));
 
@@ -691,3 +758,70 @@ pub fn render_memory_gauge(frame: &mut Frame, area: Rect, server_id: &str, state
         frame.render_widget(gauge, area);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_bounds_use_fixed_max() {
+        let bounds = y_axis_bounds(
+            [12.0, 30.0, 18.0],
+            ChartScale::Fixed,
+            100.0,
+            MIN_PERCENT_SPAN,
+        );
+        assert_eq!(bounds, [0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_fixed_bounds_grow_with_data() {
+        let bounds = y_axis_bounds([80.0, 115.0], ChartScale::Fixed, 100.0, MIN_TEMP_SPAN);
+        assert_eq!(bounds, [0.0, 115.0]);
+    }
+
+    #[test]
+    fn test_auto_bounds_add_padding() {
+        // Range 40..60 → 10% padding of 20 on each side
+        let bounds = y_axis_bounds([40.0, 50.0, 60.0], ChartScale::Auto, 100.0, MIN_TEMP_SPAN);
+        assert_eq!(bounds, [38.0, 62.0]);
+    }
+
+    #[test]
+    fn test_auto_bounds_enforce_minimum_span() {
+        // Flat line at 70 → centered 10 wide window
+        let bounds = y_axis_bounds([70.0, 70.0], ChartScale::Auto, 100.0, MIN_TEMP_SPAN);
+        assert_eq!(bounds, [65.0, 75.0]);
+    }
+
+    #[test]
+    fn test_auto_bounds_never_below_zero() {
+        let bounds = y_axis_bounds([0.5, 2.0], ChartScale::Auto, 100.0, MIN_PERCENT_SPAN);
+        assert_eq!(bounds, [0.0, MIN_PERCENT_SPAN]);
+    }
+
+    #[test]
+    fn test_auto_bounds_large_values() {
+        // 8-12 GB used on a 64 GB machine
+        let gb = (1u64 << 30) as f64;
+        let bounds = y_axis_bounds(
+            [8.0 * gb, 12.0 * gb],
+            ChartScale::Auto,
+            64.0 * gb,
+            MIN_MEMORY_SPAN,
+        );
+        assert_eq!(bounds, [7.6 * gb, 12.4 * gb]);
+    }
+
+    #[test]
+    fn test_bounds_without_data() {
+        let bounds = y_axis_bounds([], ChartScale::Auto, 50.0, MIN_TEMP_SPAN);
+        assert_eq!(bounds, [0.0, 50.0]);
+    }
+
+    #[test]
+    fn test_axis_labels() {
+        let labels = y_axis_labels([20.0, 80.0], |v| format!("{:.0}", v));
+        assert_eq!(labels, vec!["20", "50", "80"]);
+    }
+}
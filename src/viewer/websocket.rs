@@ -52,6 +52,8 @@ impl WebSocketClient {
                     http_status_code: None,
                     ssl_expiry_days: None,
                     error_message: Some(error_msg),
+                    request_bytes: None,
+                    response_bytes: None,
                 })
                 .ok();
             }
@@ -171,6 +173,8 @@ impl WebSocketClient {
             http_status_code: None,
             ssl_expiry_days: None,
             error_message: None,
+            request_bytes: None,
+            response_bytes: None,
         })
         .ok();
 
@@ -221,6 +225,8 @@ impl WebSocketClient {
                         http_status_code: None,
                         ssl_expiry_days: None,
                         error_message: Some("Connection closed by server".to_string()),
+                        request_bytes: None,
+                        response_bytes: None,
                     })
                     .ok();
                     break;
@@ -246,6 +252,8 @@ impl WebSocketClient {
             http_status_code: None,
             ssl_expiry_days: None,
             error_message: Some("Connection lost unexpectedly".to_string()),
+            request_bytes: None,
+            response_bytes: None,
         })
         .ok();
 
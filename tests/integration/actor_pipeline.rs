@@ -430,3 +430,240 @@ async fn test_recovery_alert_when_back_to_ok() {
     collector_handle.shutdown().await.unwrap();
     alert_handle.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_latency_alert_when_polls_get_slower() {
+    use guardia::config::{Alert, ResolvedLatencyLimit, Webhook};
+
+    // Webhook receiver for alerts
+    let webhook_server = start_webhook_server().await;
+
+    // Mock agent answers the startup poll quickly
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/metrics"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(create_mock_metrics_json(50.0, Some(45.0))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mock_url = url::Url::parse(&mock_server.uri()).unwrap();
+    let mut config =
+        create_test_server_config(mock_url.host_str().unwrap(), mock_url.port().unwrap());
+    config.limits = Some(guardia::config::ResolvedLimits {
+        temperature: None,
+        usage: None,
+        latency: Some(ResolvedLatencyLimit {
+            limit: None,
+            trend: Some(2),
+            grace: None,
+            alert: Some(Alert::Webhook(Webhook {
+                url: format!("{}/alert", webhook_server.uri()),
+            })),
+        }),
+        inventory: None,
+    });
+
+    let (metric_tx, _metric_rx) = broadcast::channel(256);
+    let (_service_tx, service_rx) = broadcast::channel(256);
+
+    let alert_handle = AlertHandle::spawn(
+        vec![config.clone()],
+        vec![],
+        metric_tx.subscribe(),
+        service_rx,
+    );
+    let collector_handle = CollectorHandle::spawn(
+        config,
+        metric_tx.clone(),
+        tokio::sync::broadcast::channel(16).0,
+    );
+
+    // Wait for initial auto-poll to complete
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    // Slow, then slower, then slower still
+    mock_server.reset().await;
+    for delay_ms in [100, 250, 500] {
+        Mock::given(method("GET"))
+            .and(path("/metrics"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(create_mock_metrics_json(50.0, Some(45.0)))
+                    .set_delay(std::time::Duration::from_millis(delay_ms)),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    for _ in 0..3 {
+        collector_handle.poll_now().await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(30)).await;
+    }
+
+    let messages = wait_for_webhook_messages(&webhook_server, 1).await;
+    assert_eq!(messages.len(), 1, "Expected exactly one latency alert");
+    assert!(
+        messages[0].contains("Poll Latency Alert"),
+        "Unexpected alert: {}",
+        messages[0]
+    );
+    assert!(messages[0].contains("grew over the last 2 polls"));
+
+    collector_handle.shutdown().await.unwrap();
+    alert_handle.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_cpu_inventory_change_alerts_unless_allowed() {
+    use chrono::Utc;
+    use guardia::ServerMetrics;
+    use guardia::actors::messages::MetricEvent;
+    use guardia::config::{Alert, ResolvedInventoryLimit, ResolvedLimits, Webhook};
+
+    let webhook_server = start_webhook_server().await;
+
+    let mut config = create_test_server_config("127.0.0.1", 3000);
+    config.limits = Some(ResolvedLimits {
+        temperature: None,
+        usage: None,
+        latency: None,
+        inventory: Some(ResolvedInventoryLimit {
+            allowed_cpus: vec![32],
+            allowed_arch: vec![],
+            alert: Some(Alert::Webhook(Webhook {
+                url: format!("{}/alert", webhook_server.uri()),
+            })),
+        }),
+    });
+    let server_id = "127.0.0.1:3000".to_string();
+
+    let (metric_tx, metric_rx) = broadcast::channel(16);
+    let (_service_tx, service_rx) = broadcast::channel(16);
+    let handle = AlertHandle::spawn(vec![config], vec![], metric_rx, service_rx);
+
+    let send_cpus = |total: usize, arch: &str| {
+        let mut metrics: ServerMetrics =
+            serde_json::from_value(create_mock_metrics_json(50.0, Some(50.0))).unwrap();
+        metrics.cpus.total = total;
+        metrics.cpus.arch = arch.to_string();
+        metric_tx
+            .send(MetricEvent {
+                server_id: server_id.clone(),
+                metrics,
+                timestamp: Utc::now(),
+                display_name: "Test".to_string(),
+                collection_ms: None,
+            })
+            .unwrap();
+    };
+
+    // Baseline, unchanged, resized, planned resize, arch change
+    for (total, arch) in [
+        (8, "x86_64"),
+        (8, "x86_64"),
+        (16, "x86_64"),
+        (32, "x86_64"),
+        (32, "aarch64"),
+    ] {
+        send_cpus(total, arch);
+        tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    }
+
+    let messages = wait_for_webhook_messages(&webhook_server, 2).await;
+    assert_eq!(messages.len(), 2, "unexpected alerts: {messages:?}");
+    assert!(messages.iter().all(|m| m.contains("CPU Inventory Alert")));
+    assert!(messages.iter().any(|m| m.contains("cores 8 → 16")));
+    assert!(messages.iter().any(|m| m.contains("arch x86_64 → aarch64")));
+    assert!(!messages.iter().any(|m| m.contains("→ 32")));
+
+    handle.shutdown().await;
+}
+
+#[cfg(feature = "storage-sqlite")]
+#[tokio::test]
+async fn test_cpu_inventory_change_across_restart_alerts() {
+    use chrono::Utc;
+    use guardia::ServerMetrics;
+    use guardia::actors::messages::MetricEvent;
+    use guardia::config::{Alert, ResolvedInventoryLimit, ResolvedLimits, Webhook};
+    use guardia::storage::StorageBackend;
+    use guardia::storage::sqlite::SqliteBackend;
+
+    let webhook_server = start_webhook_server().await;
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+
+    let mut config = create_test_server_config("127.0.0.1", 3000);
+    config.limits = Some(ResolvedLimits {
+        temperature: None,
+        usage: None,
+        latency: None,
+        inventory: Some(ResolvedInventoryLimit {
+            allowed_cpus: vec![],
+            allowed_arch: vec![],
+            alert: Some(Alert::Webhook(Webhook {
+                url: format!("{}/alert", webhook_server.uri()),
+            })),
+        }),
+    });
+
+    let event = |total: usize| {
+        let mut metrics: ServerMetrics =
+            serde_json::from_value(create_mock_metrics_json(50.0, Some(50.0))).unwrap();
+        metrics.cpus.total = total;
+        MetricEvent {
+            server_id: "127.0.0.1:3000".to_string(),
+            metrics,
+            timestamp: Utc::now(),
+            display_name: "Test".to_string(),
+            collection_ms: None,
+        }
+    };
+
+    // Runs the hub's storage and alert actors against the database
+    let start_hub = || async {
+        let backend = SqliteBackend::new(&db_path).await.unwrap();
+        let (metric_tx, _) = broadcast::channel(16);
+        let (service_tx, _) = broadcast::channel(16);
+        let storage = StorageHandle::spawn_with_backend(
+            metric_tx.subscribe(),
+            service_tx.subscribe(),
+            Some(Box::new(backend) as Box<dyn StorageBackend>),
+            Some(30),
+            Some(24),
+            None,
+        );
+        let alerts = AlertHandle::spawn(
+            vec![config.clone()],
+            vec![],
+            metric_tx.subscribe(),
+            service_tx.subscribe(),
+        );
+        alerts
+            .seed_inventory_from_storage(&storage, std::slice::from_ref(&config))
+            .await;
+        (metric_tx, storage, alerts)
+    };
+
+    // Before the restart the server has 8 cores
+    let (metric_tx, storage, alerts) = start_hub().await;
+    metric_tx.send(event(8)).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    storage.flush().await.unwrap();
+    alerts.shutdown().await;
+    storage.shutdown().await;
+
+    // After the restart it comes back with 16
+    let (metric_tx, storage, alerts) = start_hub().await;
+    metric_tx.send(event(16)).unwrap();
+
+    let messages = wait_for_webhook_messages(&webhook_server, 1).await;
+    assert_eq!(messages.len(), 1, "unexpected alerts: {messages:?}");
+    assert!(messages[0].contains("cores 8 → 16"));
+
+    alerts.shutdown().await;
+    storage.shutdown().await;
+}
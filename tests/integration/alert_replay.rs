@@ -0,0 +1,142 @@
+//! Integration tests for dry-run alert replay
+//!
+//! These tests verify that:
+//! - Stored history is replayed against proposed limits
+//! - Grace periods suppress short spikes like the AlertActor does
+//! - Only the requested server and time window are replayed
+//! - Alert state carries over between pages of a long history
+
+use chrono::{Duration, Utc};
+use guardia::ServerMetrics;
+use guardia::monitors::replay::{ReplayLimit, replay_history};
+use guardia::storage::StorageBackend;
+use guardia::storage::schema::MetricRow;
+use guardia::storage::sqlite::SqliteBackend;
+use tempfile::tempdir;
+
+fn cpu_row(server_id: &str, timestamp: chrono::DateTime<Utc>, cpu: f32) -> MetricRow {
+    let mut metrics = ServerMetrics::default();
+    metrics.cpus.average_usage = cpu;
+    MetricRow::from_server_metrics(
+        server_id.to_string(),
+        "Test Server".to_string(),
+        timestamp,
+        &metrics,
+    )
+}
+
+#[tokio::test]
+async fn test_replay_seeded_history() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test_replay.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    let server_id = "192.168.1.100:3000";
+    let base_time = Utc::now() - Duration::hours(2);
+
+    // One-minute samples: a 2-sample spike, a 4-sample sustained run,
+    // and a final run that has not recovered yet
+    let cpu_values = [
+        30.0, 95.0, 95.0, 30.0, 30.0, 85.0, 88.0, 90.0, 92.0, 40.0, 40.0, 81.0, 82.0, 83.0,
+    ];
+    let mut rows: Vec<MetricRow> = cpu_values
+        .iter()
+        .enumerate()
+        .map(|(i, cpu)| cpu_row(server_id, base_time + Duration::minutes(i as i64), *cpu))
+        .collect();
+
+    // Another server's data and samples outside the window must be ignored
+    rows.push(cpu_row("10.0.0.1:3000", base_time, 99.0));
+    rows.push(cpu_row(server_id, base_time - Duration::days(2), 99.0));
+
+    backend.insert_batch(rows).await.unwrap();
+
+    let limits: Vec<ReplayLimit> = vec!["cpu=80".parse().unwrap()];
+    let start = base_time - Duration::minutes(1);
+    let end = Utc::now();
+
+    // Without grace every run fires
+    let report = replay_history(&backend, server_id, start, end, &limits, 0)
+        .await
+        .unwrap();
+    assert_eq!(report.samples, cpu_values.len());
+    assert_eq!(report.results.len(), 1);
+    assert_eq!(report.results[0].alerts.len(), 3);
+
+    // With grace 2 the spike is suppressed
+    let report = replay_history(&backend, server_id, start, end, &limits, 2)
+        .await
+        .unwrap();
+    let alerts = &report.results[0].alerts;
+    assert_eq!(alerts.len(), 2);
+
+    assert_eq!(
+        alerts[0].fired_at.timestamp_millis(),
+        (base_time + Duration::minutes(7)).timestamp_millis()
+    );
+    assert_eq!(alerts[0].value, 90.0);
+    assert_eq!(
+        alerts[0].recovered_at.map(|t| t.timestamp_millis()),
+        Some((base_time + Duration::minutes(9)).timestamp_millis())
+    );
+
+    assert_eq!(
+        alerts[1].fired_at.timestamp_millis(),
+        (base_time + Duration::minutes(13)).timestamp_millis()
+    );
+    assert_eq!(alerts[1].recovered_at, None);
+
+    // A stricter limit only catches the spike
+    let limits: Vec<ReplayLimit> = vec!["cpu=95".parse().unwrap()];
+    let report = replay_history(&backend, server_id, start, end, &limits, 1)
+        .await
+        .unwrap();
+    assert_eq!(report.results[0].alerts.len(), 1);
+    assert_eq!(
+        report.results[0].alerts[0].fired_at.timestamp_millis(),
+        (base_time + Duration::minutes(2)).timestamp_millis()
+    );
+}
+
+#[tokio::test]
+async fn test_replay_history_spanning_pages() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test_replay.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    let server_id = "192.168.1.100:3000";
+    let base_time = Utc::now() - Duration::hours(4);
+
+    // One-second samples, more than a single page (10,000 rows), with a
+    // sustained run straddling the first page boundary
+    let samples = 10_010;
+    let rows: Vec<MetricRow> = (0..samples)
+        .map(|i| {
+            let cpu = if (9_998..10_004).contains(&i) {
+                90.0
+            } else {
+                30.0
+            };
+            cpu_row(server_id, base_time + Duration::seconds(i), cpu)
+        })
+        .collect();
+    backend.insert_batch(rows).await.unwrap();
+
+    let limits: Vec<ReplayLimit> = vec!["cpu=80".parse().unwrap()];
+    let report = replay_history(&backend, server_id, base_time, Utc::now(), &limits, 3)
+        .await
+        .unwrap();
+
+    assert_eq!(report.samples, samples as usize);
+
+    let alerts = &report.results[0].alerts;
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(
+        alerts[0].fired_at.timestamp_millis(),
+        (base_time + Duration::seconds(10_001)).timestamp_millis()
+    );
+    assert_eq!(
+        alerts[0].recovered_at.map(|t| t.timestamp_millis()),
+        Some((base_time + Duration::seconds(10_004)).timestamp_millis())
+    );
+}
@@ -18,14 +18,16 @@ use guardia::{
         storage::StorageHandle,
     },
     api::{ApiConfig, ApiState, spawn_api_server},
-    config::{HttpMethod, ResolvedServerConfig, ResolvedServiceConfig},
-    storage::{StorageBackend, sqlite::SqliteBackend},
+    config::{CheckType, HttpMethod, ResolvedServerConfig, ResolvedServiceConfig},
+    storage::{StorageBackend, schema::MetricRow, sqlite::SqliteBackend},
 };
 use serde_json::Value;
 use std::net::SocketAddr;
 use tempfile::tempdir;
 use tokio::sync::broadcast;
 
+use crate::helpers::{start_webhook_server, wait_for_webhook_messages};
+
 // Helper to create test API server
 async fn spawn_test_api(
     collectors: Vec<CollectorHandle>,
@@ -126,6 +128,112 @@ async fn test_health_endpoint_returns_ok() {
     assert!(json["timestamp"].is_string());
 }
 
+#[cfg(feature = "api")]
+#[tokio::test]
+async fn test_health_endpoint_reports_alert_channels() {
+    use guardia::actors::alert::AlertHandle;
+    use guardia::config::{Alert, AlertHealthConfig, Webhook};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    // Setup - one reachable webhook, one pointing at a missing endpoint and
+    // one that refuses the probe
+    let mock_server = MockServer::start().await;
+    Mock::given(method("HEAD"))
+        .and(path("/hooks/valid"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("HEAD"))
+        .and(path("/hooks/rejecting"))
+        .respond_with(ResponseTemplate::new(405))
+        .mount(&mock_server)
+        .await;
+
+    let service = |name: &str, hook: &str| ResolvedServiceConfig {
+        name: name.to_string(),
+        url: "http://127.0.0.1:1/health".to_string(),
+        interval: 60,
+        cron: None,
+        timeout: 10,
+        check: CheckType::Http,
+        method: HttpMethod::Get,
+        expected_status: None,
+        body_pattern: None,
+        max_body_bytes: None,
+        grace: None,
+        alert: Some(Alert::Webhook(Webhook {
+            url: format!("{}/hooks/{}", mock_server.uri(), hook),
+        })),
+    };
+
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn(metric_tx.subscribe(), service_rx);
+    let alerts = AlertHandle::spawn_with_health_check(
+        vec![],
+        vec![
+            service("api", "valid"),
+            service("web", "invalid"),
+            service("db", "rejecting"),
+        ],
+        metric_tx.subscribe(),
+        service_tx.subscribe(),
+        &AlertHealthConfig::default(),
+    );
+
+    // Wait for the startup check to complete
+    for _ in 0..50 {
+        if !alerts.channel_health().await.is_empty() {
+            break;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+    }
+
+    let state = ApiState::new(storage, alerts, vec![], vec![], metric_tx, service_tx);
+    let config = ApiConfig {
+        bind_addr: "127.0.0.1:0".parse().unwrap(),
+        auth_token: Some("test-token".to_string()),
+        enable_cors: true,
+    };
+    let addr = spawn_api_server(config, state).await.unwrap();
+
+    // Test
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("http://{}/api/v1/health", addr))
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["status"], "degraded");
+
+    let channels = json["alert_channels"].as_array().unwrap();
+    assert_eq!(channels.len(), 3);
+
+    let healthy: Vec<_> = channels.iter().filter(|c| c["healthy"] == true).collect();
+    let unhealthy: Vec<_> = channels.iter().filter(|c| c["healthy"] == false).collect();
+    assert_eq!(healthy.len(), 1);
+    assert_eq!(healthy[0]["status_code"], 200);
+    assert_eq!(unhealthy.len(), 2);
+    let mut codes: Vec<_> = unhealthy
+        .iter()
+        .map(|c| c["status_code"].as_u64().unwrap())
+        .collect();
+    codes.sort();
+    assert_eq!(codes, vec![404, 405]);
+
+    // Webhook tokens are not exposed
+    assert!(channels.iter().all(|c| {
+        let target = c["target"].as_str().unwrap();
+        !target.contains("valid") && target.ends_with("/hooks/***")
+    }));
+}
+
 #[cfg(feature = "api")]
 #[tokio::test]
 async fn test_stats_endpoint_returns_storage_info() {
@@ -171,6 +279,7 @@ async fn test_list_servers_with_no_metrics_shows_unknown() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     // Create a collector
@@ -229,6 +338,7 @@ async fn test_list_servers_with_recent_metrics_shows_up() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     // Create a collector
@@ -252,6 +362,7 @@ async fn test_list_servers_with_recent_metrics_shows_up() {
         display_name: "Test Server".to_string(),
         metrics: create_test_metrics(),
         timestamp: Utc::now(),
+        collection_ms: None,
     };
     metric_tx.send(event).unwrap();
 
@@ -296,6 +407,7 @@ async fn test_list_servers_with_stale_metrics_shows_stale() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     // Create a collector
@@ -319,6 +431,7 @@ async fn test_list_servers_with_stale_metrics_shows_stale() {
         display_name: "Test Server".to_string(),
         metrics: create_test_metrics(),
         timestamp: Utc::now() - Duration::minutes(10),
+        collection_ms: None,
     };
     metric_tx.send(event).unwrap();
 
@@ -360,6 +473,7 @@ async fn test_list_services_with_recent_up_check_shows_up() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     // Create a service monitor
@@ -367,10 +481,13 @@ async fn test_list_services_with_recent_up_check_shows_up() {
         name: "Test Service".to_string(),
         url: "http://example.com".to_string(),
         interval: 60,
+        cron: None,
         timeout: 10,
+        check: CheckType::Http,
         method: HttpMethod::Get,
         expected_status: None,
         body_pattern: None,
+        max_body_bytes: None,
         grace: None,
         alert: None,
     };
@@ -386,6 +503,8 @@ async fn test_list_services_with_recent_up_check_shows_up() {
         http_status_code: Some(200),
         ssl_expiry_days: None,
         error_message: None,
+        request_bytes: Some(0),
+        response_bytes: Some(2),
     };
     service_tx.send(event).unwrap();
 
@@ -420,6 +539,559 @@ async fn test_list_services_with_recent_up_check_shows_up() {
     assert_eq!(service["last_status"], "up");
 }
 
+#[cfg(feature = "api")]
+#[cfg(feature = "storage-sqlite")]
+#[tokio::test]
+async fn test_uptime_counts_compacted_runs_after_raw_checks_are_deleted() {
+    use guardia::storage::schema::ServiceCheckRow;
+
+    // Setup
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    let now = chrono::DateTime::from_timestamp_millis(Utc::now().timestamp_millis()).unwrap();
+    let check = |minutes_ago: i64, status: ServiceStatus| {
+        ServiceCheckRow::from_event(&ServiceCheckEvent {
+            service_name: "api".to_string(),
+            url: "http://example.com".to_string(),
+            timestamp: now - Duration::minutes(minutes_ago),
+            status,
+            response_time_ms: Some(100),
+            http_status_code: Some(200),
+            ssl_expiry_days: None,
+            error_message: None,
+            request_bytes: None,
+            response_bytes: Some(2),
+        })
+    };
+
+    // Two hours of checks with a 10 minute outage, compacted and then
+    // removed by retention cleanup
+    let old_checks = (61..=120)
+        .rev()
+        .map(|m| {
+            let status = if (90..100).contains(&m) {
+                ServiceStatus::Down
+            } else {
+                ServiceStatus::Up
+            };
+            check(m, status)
+        })
+        .collect();
+    backend
+        .insert_service_checks_batch(old_checks)
+        .await
+        .unwrap();
+    let watermark = now - Duration::minutes(60);
+    assert_eq!(backend.compact_service_checks(watermark).await.unwrap(), 60);
+    assert_eq!(
+        backend.cleanup_old_service_checks(watermark).await.unwrap(),
+        60
+    );
+
+    // Raw checks after the watermark, not compacted yet
+    let recent_checks = (1..=10).map(|m| check(m, ServiceStatus::Up)).collect();
+    backend
+        .insert_service_checks_batch(recent_checks)
+        .await
+        .unwrap();
+
+    let (metric_tx, metric_rx) = broadcast::channel(16);
+    let (service_tx, _) = broadcast::channel(16);
+    let storage = StorageHandle::spawn_with_backend(
+        metric_rx,
+        service_tx.subscribe(),
+        Some(Box::new(backend) as Box<dyn StorageBackend>),
+        Some(30),
+        Some(24),
+        None,
+    );
+
+    let addr = spawn_test_api(vec![], vec![], storage, metric_tx, service_tx).await;
+
+    // Test
+    let response = reqwest::Client::new()
+        .get(format!("http://{}/api/v1/services/api/uptime", addr))
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["total_checks"], 70);
+    assert_eq!(json["successful_checks"], 60);
+    let uptime = json["uptime_percentage"].as_f64().unwrap();
+    assert!((uptime - 60.0 / 70.0 * 100.0).abs() < 1e-9);
+    assert_eq!(json["avg_response_time_ms"], 100.0);
+}
+
+#[cfg(feature = "api")]
+#[cfg(feature = "storage-sqlite")]
+#[tokio::test]
+async fn test_system_history_returns_distinct_snapshots() {
+    // Setup
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    // Storage keeps millisecond precision, so align the base timestamp to it
+    let base = chrono::DateTime::from_timestamp_millis(
+        (Utc::now() - Duration::hours(1)).timestamp_millis(),
+    )
+    .unwrap();
+
+    // Two samples on the original kernel, two after an upgrade, one after a rename
+    let original = create_test_metrics();
+    let mut upgraded = create_test_metrics();
+    upgraded.system.kernel_version = Some("6.1.0".to_string());
+    let mut renamed = upgraded.clone();
+    renamed.system.host_name = Some("renamed-host".to_string());
+
+    let samples = [&original, &original, &upgraded, &upgraded, &renamed];
+    let rows = samples
+        .iter()
+        .enumerate()
+        .map(|(i, metrics)| {
+            MetricRow::from_server_metrics(
+                "192.168.1.100:3000".to_string(),
+                "Test Server".to_string(),
+                base + Duration::minutes(i as i64),
+                metrics,
+            )
+        })
+        .collect();
+    backend.insert_batch(rows).await.unwrap();
+
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn_with_backend(
+        metric_tx.subscribe(),
+        service_rx,
+        Some(Box::new(backend) as Box<dyn StorageBackend>),
+        Some(30),
+        Some(24),
+        None,
+    );
+
+    let addr = spawn_test_api(vec![], vec![], storage, metric_tx, service_tx).await;
+
+    // Test
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!(
+            "http://{}/api/v1/servers/192.168.1.100:3000/system-history",
+            addr
+        ))
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["count"], 3);
+
+    let at = |minutes: i64| (base + Duration::minutes(minutes)).to_rfc3339();
+    let snapshots = &json["snapshots"];
+
+    assert_eq!(snapshots[0]["system"]["kernel_version"], "5.15.0");
+    assert_eq!(snapshots[0]["first_seen"], at(0));
+    assert_eq!(snapshots[0]["last_seen"], at(1));
+    assert_eq!(snapshots[0]["sample_count"], 2);
+
+    assert_eq!(snapshots[1]["system"]["kernel_version"], "6.1.0");
+    assert_eq!(snapshots[1]["system"]["host_name"], "test-host");
+    assert_eq!(snapshots[1]["first_seen"], at(2));
+    assert_eq!(snapshots[1]["last_seen"], at(3));
+    assert_eq!(snapshots[1]["sample_count"], 2);
+
+    assert_eq!(snapshots[2]["system"]["host_name"], "renamed-host");
+    assert_eq!(snapshots[2]["first_seen"], at(4));
+    assert_eq!(snapshots[2]["last_seen"], at(4));
+    assert_eq!(snapshots[2]["sample_count"], 1);
+}
+
+#[cfg(feature = "api")]
+#[cfg(feature = "storage-sqlite")]
+#[tokio::test]
+async fn test_system_history_counts_every_sample_across_pages() {
+    // Setup
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    let base = chrono::DateTime::from_timestamp_millis(
+        (Utc::now() - Duration::hours(1)).timestamp_millis(),
+    )
+    .unwrap();
+
+    // More samples than fit on one page (10,000), one per millisecond
+    let metrics = create_test_metrics();
+    let samples = 10_050;
+    let rows = (0..samples)
+        .map(|i| {
+            MetricRow::from_server_metrics(
+                "192.168.1.100:3000".to_string(),
+                "Test Server".to_string(),
+                base + Duration::milliseconds(i),
+                &metrics,
+            )
+        })
+        .collect();
+    backend.insert_batch(rows).await.unwrap();
+
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn_with_backend(
+        metric_tx.subscribe(),
+        service_rx,
+        Some(Box::new(backend) as Box<dyn StorageBackend>),
+        Some(30),
+        Some(24),
+        None,
+    );
+
+    let addr = spawn_test_api(vec![], vec![], storage, metric_tx, service_tx).await;
+
+    // Test
+    let response = reqwest::Client::new()
+        .get(format!(
+            "http://{}/api/v1/servers/192.168.1.100:3000/system-history",
+            addr
+        ))
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["count"], 1);
+    assert_eq!(json["snapshots"][0]["sample_count"], samples);
+    assert_eq!(
+        json["snapshots"][0]["last_seen"],
+        (base + Duration::milliseconds(samples - 1)).to_rfc3339()
+    );
+}
+
+#[cfg(feature = "api")]
+#[cfg(feature = "storage-sqlite")]
+#[tokio::test]
+async fn test_import_historical_metrics_stores_without_alerting() {
+    use guardia::actors::alert::AlertHandle;
+    use guardia::config::{Alert, ResolvedLimit, ResolvedLimits, Webhook};
+
+    // Setup - webhook that records any alert sent
+    let mock_server = start_webhook_server().await;
+
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    let (metric_tx, _) = broadcast::channel(256);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn_with_backend(
+        metric_tx.subscribe(),
+        service_rx,
+        Some(Box::new(backend) as Box<dyn StorageBackend>),
+        Some(30),
+        Some(24),
+        None,
+    );
+
+    // Server with a CPU limit that every imported sample crosses
+    let config = ResolvedServerConfig {
+        ip: "192.168.1.100".parse().unwrap(),
+        port: 3000,
+        interval: 30,
+        token: None,
+        display: Some("Test Server".to_string()),
+        limits: Some(ResolvedLimits {
+            temperature: None,
+            usage: Some(ResolvedLimit {
+                limit: 80,
+                grace: None,
+                alert: Some(Alert::Webhook(Webhook {
+                    url: format!("{}/hook", mock_server.uri()),
+                })),
+            }),
+            latency: None,
+            inventory: None,
+        }),
+    };
+    let _alerts = AlertHandle::spawn(
+        vec![config.clone()],
+        vec![],
+        metric_tx.subscribe(),
+        service_tx.subscribe(),
+    );
+    let collector = CollectorHandle::spawn(
+        config,
+        metric_tx.clone(),
+        tokio::sync::broadcast::channel(16).0,
+    );
+
+    let addr = spawn_test_api(
+        vec![collector],
+        vec![],
+        storage.clone(),
+        metric_tx.clone(),
+        service_tx,
+    )
+    .await;
+
+    // Storage keeps millisecond precision, so align the base timestamp to it
+    let base = chrono::DateTime::from_timestamp_millis(
+        (Utc::now() - Duration::hours(2)).timestamp_millis(),
+    )
+    .unwrap();
+
+    let mut hot = create_test_metrics();
+    hot.cpus.average_usage = 95.0;
+    let samples: Vec<Value> = (0..5)
+        .map(|i| {
+            serde_json::json!({
+                "timestamp": (base + Duration::minutes(i)).to_rfc3339(),
+                "metrics": hot,
+            })
+        })
+        .collect();
+
+    // Test - import threshold-crossing history
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!(
+            "http://{}/api/v1/servers/192.168.1.100:3000/import",
+            addr
+        ))
+        .header("Authorization", "Bearer test-token")
+        .json(&serde_json::json!({ "samples": samples }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["imported"], 5);
+    assert_eq!(json["historical"], true);
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    storage.flush().await.unwrap();
+
+    // Rows are queryable
+    let response = client
+        .get(format!(
+            "http://{}/api/v1/servers/192.168.1.100:3000/metrics",
+            addr
+        ))
+        .query(&[("start", (base - Duration::minutes(1)).to_rfc3339())])
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["count"], 5);
+
+    // No alert was sent
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+
+    // Live data crossing the same limit still alerts
+    metric_tx
+        .send(MetricEvent {
+            server_id: "192.168.1.100:3000".to_string(),
+            display_name: "Test Server".to_string(),
+            metrics: hot,
+            timestamp: Utc::now(),
+            collection_ms: None,
+        })
+        .unwrap();
+
+    assert_eq!(wait_for_webhook_messages(&mock_server, 1).await.len(), 1);
+}
+
+#[cfg(feature = "api")]
+#[tokio::test]
+async fn test_import_for_unknown_server_returns_404() {
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn(metric_tx.subscribe(), service_rx);
+
+    let addr = spawn_test_api(vec![], vec![], storage, metric_tx, service_tx).await;
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!(
+            "http://{}/api/v1/servers/10.0.0.1:3000/import",
+            addr
+        ))
+        .header("Authorization", "Bearer test-token")
+        .json(&serde_json::json!({ "samples": [] }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[cfg(feature = "api")]
+#[tokio::test]
+async fn test_import_disabled_without_auth_token() {
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn(metric_tx.subscribe(), service_rx);
+
+    let state = ApiState::new(
+        storage,
+        guardia::actors::alert::AlertHandle::spawn(
+            vec![],
+            vec![],
+            metric_tx.subscribe(),
+            service_tx.subscribe(),
+        ),
+        vec![],
+        vec![],
+        metric_tx,
+        service_tx,
+    );
+    let config = ApiConfig {
+        bind_addr: "127.0.0.1:0".parse().unwrap(),
+        auth_token: None,
+        enable_cors: true,
+    };
+    let addr = spawn_api_server(config, state).await.unwrap();
+
+    let response = reqwest::Client::new()
+        .post(format!(
+            "http://{}/api/v1/servers/192.168.1.100:3000/import",
+            addr
+        ))
+        .json(&serde_json::json!({ "samples": [] }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[cfg(feature = "api")]
+#[cfg(feature = "storage-sqlite")]
+#[tokio::test]
+async fn test_delete_metrics_before_cutoff() {
+    // Setup
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let backend = SqliteBackend::new(&db_path).await.unwrap();
+
+    // Storage keeps millisecond precision, so align the base timestamp to it
+    let now = chrono::DateTime::from_timestamp_millis(Utc::now().timestamp_millis()).unwrap();
+    let cutoff = now - Duration::hours(12);
+
+    // Hourly samples over the last day for two servers
+    let metrics = create_test_metrics();
+    let rows = (0..24)
+        .flat_map(|hours| {
+            ["192.168.1.100:3000", "192.168.1.101:3000"].map(|server_id| {
+                MetricRow::from_server_metrics(
+                    server_id.to_string(),
+                    "Test Server".to_string(),
+                    now - Duration::hours(hours) - Duration::minutes(30),
+                    &metrics,
+                )
+            })
+        })
+        .collect();
+    backend.insert_batch(rows).await.unwrap();
+
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn_with_backend(
+        metric_tx.subscribe(),
+        service_rx,
+        Some(Box::new(backend) as Box<dyn StorageBackend>),
+        Some(30),
+        Some(24),
+        None,
+    );
+
+    let addr = spawn_test_api(vec![], vec![], storage, metric_tx, service_tx).await;
+    let client = reqwest::Client::new();
+
+    // Without confirmation nothing is deleted
+    let response = client
+        .delete(format!("http://{}/api/v1/metrics", addr))
+        .query(&[("before", cutoff.to_rfc3339())])
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Test
+    let response = client
+        .delete(format!("http://{}/api/v1/metrics", addr))
+        .query(&[
+            ("before", cutoff.to_rfc3339()),
+            ("confirm", "true".to_string()),
+        ])
+        .header("Authorization", "Bearer test-token")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json: Value = response.json().await.unwrap();
+    assert_eq!(json["metrics_deleted"], 24); // 12 per server
+    assert_eq!(json["service_checks_deleted"], 0);
+
+    // Only rows after the cutoff remain
+    for server_id in ["192.168.1.100:3000", "192.168.1.101:3000"] {
+        let response = client
+            .get(format!(
+                "http://{}/api/v1/servers/{}/metrics",
+                addr, server_id
+            ))
+            .query(&[("start", (now - Duration::days(2)).to_rfc3339())])
+            .header("Authorization", "Bearer test-token")
+            .send()
+            .await
+            .unwrap();
+
+        let json: Value = response.json().await.unwrap();
+        assert_eq!(json["count"], 12);
+        let oldest = json["metrics"][0]["timestamp"].as_str().unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(oldest).unwrap() >= cutoff);
+    }
+}
+
+#[cfg(feature = "api")]
+#[tokio::test]
+async fn test_delete_metrics_requires_token() {
+    let (metric_tx, _) = broadcast::channel(16);
+    let (service_tx, service_rx) = broadcast::channel(16);
+    let storage = StorageHandle::spawn(metric_tx.subscribe(), service_rx);
+
+    let addr = spawn_test_api(vec![], vec![], storage, metric_tx, service_tx).await;
+
+    let response = reqwest::Client::new()
+        .delete(format!("http://{}/api/v1/metrics", addr))
+        .query(&[
+            ("before", Utc::now().to_rfc3339()),
+            ("confirm", "true".to_string()),
+        ])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
 #[cfg(feature = "api")]
 #[tokio::test]
 async fn test_api_with_valid_token_succeeds() {
@@ -177,6 +177,7 @@ async fn test_channel_backpressure_handling() {
             },
             timestamp: Utc::now(),
             display_name: format!("Test {i}"),
+            collection_ms: None,
         };
 
         let _ = metric_tx.send(event);
@@ -147,6 +147,7 @@ async fn test_storage_actor_handles_broadcast_lag() {
             },
             timestamp: Utc::now(),
             display_name: format!("Test {i}"),
+            collection_ms: None,
         };
 
         let _ = metric_tx.send(event);
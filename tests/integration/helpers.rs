@@ -3,6 +3,8 @@
 use guardia::config::{ResolvedLimit, ResolvedLimits, ResolvedServerConfig};
 use std::net::IpAddr;
 use std::str::FromStr;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 pub fn create_test_server_config(ip: &str, port: u16) -> ResolvedServerConfig {
     ResolvedServerConfig {
@@ -35,6 +37,8 @@ pub fn create_test_server_with_limits(
             grace: Some(grace),
             alert: None,
         }),
+        latency: None,
+        inventory: None,
     });
 
     config
@@ -66,3 +70,35 @@ pub fn create_mock_metrics_json(cpu_usage: f32, temperature: Option<f32>) -> ser
         }
     })
 }
+
+/// Start a mock webhook endpoint that accepts every alert POSTed to it
+pub async fn start_webhook_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    server
+}
+
+/// Wait until a mock webhook endpoint received at least `count` alerts
+///
+/// Alert delivery happens asynchronously, so this polls for up to 5 seconds.
+/// Returns the bodies of all requests received so far.
+pub async fn wait_for_webhook_messages(server: &MockServer, count: usize) -> Vec<String> {
+    let mut messages = vec![];
+    for _ in 0..50 {
+        messages = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| String::from_utf8_lossy(&request.body).to_string())
+            .collect();
+        if messages.len() >= count {
+            break;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+    messages
+}
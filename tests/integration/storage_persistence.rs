@@ -36,6 +36,7 @@ async fn test_full_persistence_pipeline() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30), // 30 days retention
         Some(24), // cleanup every 24 hours
+        None,
     );
 
     // Give actor time to initialize
@@ -50,6 +51,7 @@ async fn test_full_persistence_pipeline() {
         display_name: "Test Server".to_string(),
         timestamp: base_time,
         metrics: ServerMetrics::default(),
+        collection_ms: None,
     };
 
     let event2 = MetricEvent {
@@ -57,6 +59,7 @@ async fn test_full_persistence_pipeline() {
         display_name: "Test Server".to_string(),
         timestamp: base_time + Duration::seconds(60), // 60 seconds later
         metrics: ServerMetrics::default(),
+        collection_ms: None,
     };
 
     // Send metrics via broadcast
@@ -188,6 +191,7 @@ async fn test_batch_write_performance() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         None, // No retention cleanup for this test
         None, // No cleanup interval for this test
+        None,
     );
 
     let server_id = "batch-test:3000".to_string();
@@ -199,6 +203,7 @@ async fn test_batch_write_performance() {
             display_name: "Batch Test".to_string(),
             timestamp: Utc::now() + Duration::seconds(i),
             metrics: ServerMetrics::default(),
+            collection_ms: None,
         };
         metric_tx.send(event).unwrap();
     }
@@ -318,6 +323,7 @@ async fn test_service_check_persistence() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     // Give actor time to initialize
@@ -336,6 +342,8 @@ async fn test_service_check_persistence() {
         http_status_code: Some(200),
         ssl_expiry_days: None,
         error_message: None,
+        request_bytes: Some(0),
+        response_bytes: Some(512),
     };
 
     let check2 = ServiceCheckEvent {
@@ -347,6 +355,8 @@ async fn test_service_check_persistence() {
         http_status_code: None,
         ssl_expiry_days: None,
         error_message: Some("Connection timeout".to_string()),
+        request_bytes: None,
+        response_bytes: None,
     };
 
     let check3 = ServiceCheckEvent {
@@ -358,6 +368,8 @@ async fn test_service_check_persistence() {
         http_status_code: Some(200),
         ssl_expiry_days: None,
         error_message: None,
+        request_bytes: Some(0),
+        response_bytes: Some(64),
     };
 
     // Send service checks via broadcast
@@ -390,6 +402,9 @@ async fn test_service_check_persistence() {
     // Verify check data
     assert_eq!(latest_checks[2].status, ServiceStatus::Up);
     assert_eq!(latest_checks[2].response_time_ms, Some(125));
+    assert_eq!(latest_checks[2].request_bytes, Some(0));
+    assert_eq!(latest_checks[2].response_bytes, Some(512));
+    assert_eq!(latest_checks[1].response_bytes, None);
     assert_eq!(latest_checks[1].status, ServiceStatus::Down);
     assert_eq!(
         latest_checks[1].error_message,
@@ -424,6 +439,7 @@ async fn test_service_uptime_calculation() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     // Give actor time to initialize
@@ -463,6 +479,8 @@ async fn test_service_uptime_calculation() {
             } else {
                 None
             },
+            request_bytes: None,
+            response_bytes: None,
         });
     }
 
@@ -534,6 +552,7 @@ async fn test_service_check_query_range() {
         Some(Box::new(backend) as Box<dyn StorageBackend>),
         Some(30),
         Some(24),
+        None,
     );
 
     tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
@@ -553,6 +572,8 @@ async fn test_service_check_query_range() {
             http_status_code: Some(200),
             ssl_expiry_days: None,
             error_message: None,
+            request_bytes: None,
+            response_bytes: None,
         };
         service_tx.send(check).unwrap();
     }
@@ -16,6 +16,10 @@ mod concurrency;
 #[path = "integration/storage_persistence.rs"]
 mod storage_persistence;
 
+#[cfg(feature = "storage-sqlite")]
+#[path = "integration/alert_replay.rs"]
+mod alert_replay;
+
 #[path = "integration/service_monitoring.rs"]
 mod service_monitoring;
 